@@ -4,6 +4,16 @@ use anyhow::Context;
 use path_slash::PathBufExt;
 
 fn main() -> anyhow::Result<()> {
+    if cfg!(feature = "pure-rust") {
+        // `src/` already carries a native Rust port of the modeling pipeline
+        // (see `engine.rs`, `position_modeler.rs`, `state_modeler.rs`, ...) behind the same
+        // public API as the autocxx bindings built below. With this feature enabled there is
+        // nothing for this build script to do: no cmake, no C++20 toolchain, no autocxx, which
+        // is what lets the crate build on targets like `wasm32-unknown-unknown`.
+        eprintln!("### pure-rust feature enabled: skipping cmake/autocxx build ###");
+        return Ok(());
+    }
+
     let out_dir = PathBuf::from(std::env::var("OUT_DIR")?);
     let install_lib_dir = out_dir.join("lib");
     let install_include_dir = out_dir.join("include");
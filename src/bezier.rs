@@ -0,0 +1,172 @@
+use crate::utils::{dist, interp2, nearest_point_on_segment};
+use crate::ModelerResult;
+
+/// Control points of a single cubic Bézier segment, in the same coordinate space as
+/// [ModelerResult::pos]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezier {
+    pub p0: (f32, f32),
+    pub p1: (f32, f32),
+    pub p2: (f32, f32),
+    pub p3: (f32, f32),
+}
+
+/// number of points sampled along a candidate curve when measuring its deviation
+/// from the modeled points it is meant to approximate
+const CURVE_SAMPLES: usize = 16;
+
+fn sample(bezier: &CubicBezier, t: f32) -> (f32, f32) {
+    let mt = 1.0 - t;
+    let a = mt * mt * mt;
+    let b = 3.0 * mt * mt * t;
+    let c = 3.0 * mt * t * t;
+    let d = t * t * t;
+    (
+        a * bezier.p0.0 + b * bezier.p1.0 + c * bezier.p2.0 + d * bezier.p3.0,
+        a * bezier.p0.1 + b * bezier.p1.1 + c * bezier.p2.1 + d * bezier.p3.1,
+    )
+}
+
+/// fits a single cubic Bézier through `first` and `last`, using their velocities
+/// (scaled by a third of the elapsed time) as tangent estimates for the control points
+fn fit_segment(first: &ModelerResult, last: &ModelerResult) -> CubicBezier {
+    let dt = (last.time - first.time) as f32;
+    CubicBezier {
+        p0: first.pos,
+        p1: (
+            first.pos.0 + first.velocity.0 * dt / 3.0,
+            first.pos.1 + first.velocity.1 * dt / 3.0,
+        ),
+        p2: (
+            last.pos.0 - last.velocity.0 * dt / 3.0,
+            last.pos.1 - last.velocity.1 * dt / 3.0,
+        ),
+        p3: last.pos,
+    }
+}
+
+/// the largest distance from any of `points` to its nearest point on `bezier`,
+/// approximated by the polyline through the curve sampled at [CURVE_SAMPLES] evenly spaced
+/// parameter intervals
+fn max_deviation(bezier: &CubicBezier, points: &[ModelerResult]) -> f32 {
+    let curve_samples: Vec<(f32, f32)> = (0..=CURVE_SAMPLES)
+        .map(|i| sample(bezier, i as f32 / CURVE_SAMPLES as f32))
+        .collect();
+
+    points
+        .iter()
+        .map(|result| {
+            curve_samples
+                .windows(2)
+                .map(|pair| {
+                    let r = nearest_point_on_segment(pair[0], pair[1], result.pos);
+                    dist(result.pos, interp2(pair[0], pair[1], r))
+                })
+                .fold(f32::INFINITY, f32::min)
+        })
+        .fold(0.0, f32::max)
+}
+
+/// Fits a chain of cubic Bézier segments through a run of modeled results, using the
+/// sampled velocities as tangent estimates (`P1 = P0 + v0 * dt/3`, `P2 = P3 - v3 * dt/3`).
+/// A span is greedily grown as long as the maximum perpendicular deviation of the
+/// intermediate modeled points from the candidate curve stays within `tolerance`; once it
+/// would be exceeded, the span is closed off and a new segment starts from its last point.
+///
+/// Returns an empty vector if `results` has fewer than two points.
+pub fn fit_cubic_beziers(results: &[ModelerResult], tolerance: f32) -> Vec<CubicBezier> {
+    if results.len() < 2 {
+        return Vec::new();
+    }
+
+    let mut segments = Vec::new();
+    let mut start = 0;
+
+    while start < results.len() - 1 {
+        let mut end = start + 1;
+        let mut best = fit_segment(&results[start], &results[end]);
+
+        while end + 1 < results.len() {
+            let candidate_end = end + 1;
+            let candidate = fit_segment(&results[start], &results[candidate_end]);
+
+            if max_deviation(&candidate, &results[start..=candidate_end]) > tolerance {
+                break;
+            }
+
+            end = candidate_end;
+            best = candidate;
+        }
+
+        segments.push(best);
+        start = end;
+    }
+
+    segments
+}
+
+#[cfg(test)]
+mod test_bezier {
+    use super::*;
+
+    fn result_at(pos: (f32, f32), velocity: (f32, f32), time: f64) -> ModelerResult {
+        ModelerResult {
+            pos,
+            velocity,
+            time,
+            ..ModelerResult::default()
+        }
+    }
+
+    #[test]
+    fn empty_and_single_point_produce_no_segments() {
+        assert!(fit_cubic_beziers(&[], 0.01).is_empty());
+        assert!(fit_cubic_beziers(&[result_at((0.0, 0.0), (0.0, 0.0), 0.0)], 0.01).is_empty());
+    }
+
+    #[test]
+    fn straight_line_fits_in_a_single_segment() {
+        let results = vec![
+            result_at((0.0, 0.0), (10.0, 0.0), 0.0),
+            result_at((1.0, 0.0), (10.0, 0.0), 0.1),
+            result_at((2.0, 0.0), (10.0, 0.0), 0.2),
+            result_at((3.0, 0.0), (10.0, 0.0), 0.3),
+        ];
+
+        let segments = fit_cubic_beziers(&results, 0.01);
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].p0, (0.0, 0.0));
+        assert_eq!(segments[0].p3, (3.0, 0.0));
+    }
+
+    #[test]
+    fn sharp_turn_requires_multiple_segments() {
+        let results = vec![
+            result_at((0.0, 0.0), (10.0, 0.0), 0.0),
+            result_at((1.0, 0.0), (10.0, 0.0), 0.1),
+            result_at((2.0, 0.0), (0.0, -10.0), 0.2),
+            result_at((2.0, -1.0), (0.0, -10.0), 0.3),
+            result_at((2.0, -2.0), (0.0, -10.0), 0.4),
+        ];
+
+        let segments = fit_cubic_beziers(&results, 0.05);
+        assert!(segments.len() > 1);
+        assert_eq!(segments.first().unwrap().p0, (0.0, 0.0));
+        assert_eq!(segments.last().unwrap().p3, (2.0, -2.0));
+    }
+
+    #[test]
+    fn segments_chain_end_to_end() {
+        let results = vec![
+            result_at((0.0, 0.0), (10.0, 0.0), 0.0),
+            result_at((1.0, 0.0), (10.0, 0.0), 0.1),
+            result_at((2.0, 0.0), (0.0, -10.0), 0.2),
+            result_at((2.0, -1.0), (0.0, -10.0), 0.3),
+        ];
+
+        let segments = fit_cubic_beziers(&results, 0.05);
+        for pair in segments.windows(2) {
+            assert_eq!(pair[0].p3, pair[1].p0);
+        }
+    }
+}
@@ -1,9 +1,52 @@
-use std::vec;
+use std::collections::VecDeque;
 
 use super::*;
 
+use crate::position_modeler::PositionModeler;
+use crate::prediction::KalmanPredictor;
+use crate::prediction::KalmanPredictorParams;
+use crate::state_modeler::StateModeler;
 use crate::utils::interp;
 use crate::utils::normalize01_32;
+use crate::{ElementError, ElementOrderError, PredictionParams};
+
+/// Destination for [StrokeModeler::update_to]'s emitted results. Implemented for both a
+/// growable `Vec<ModelerResult>` and, behind the `fixed-buffer` feature, the fixed-capacity
+/// [FixedResultBuffer] — so [StrokeModeler::update_into] can drive `update_to`'s own
+/// per-event/per-substep emission straight into caller-provided storage, with no
+/// intermediate `Vec<ModelerResult>` allocated and discarded in between.
+pub trait ResultSink {
+    /// Appends one result, or reports that the destination is full (only possible for a
+    /// fixed-capacity destination; always `Ok` for a `Vec`).
+    fn push_result(&mut self, result: ModelerResult) -> Result<(), ModelerError>;
+    fn len(&self) -> usize;
+    /// Pre-sizes the destination for `additional` more results, where that's possible
+    /// (a no-op for a fixed-capacity destination, which is already sized).
+    fn reserve(&mut self, _additional: usize) {}
+}
+
+impl ResultSink for Vec<ModelerResult> {
+    fn push_result(&mut self, result: ModelerResult) -> Result<(), ModelerError> {
+        self.push(result);
+        Ok(())
+    }
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+    fn reserve(&mut self, additional: usize) {
+        Vec::reserve(self, additional);
+    }
+}
+
+#[cfg(feature = "fixed-buffer")]
+impl<const N: usize> ResultSink for FixedResultBuffer<N> {
+    fn push_result(&mut self, result: ModelerResult) -> Result<(), ModelerError> {
+        self.push(result)
+    }
+    fn len(&self) -> usize {
+        FixedResultBuffer::len(self)
+    }
+}
 
 /// smooth out the input position from high frequency noise
 /// uses a moving average of position and interpolating between this
@@ -12,7 +55,8 @@ use crate::utils::normalize01_32;
 ///
 /// wrapper time to include all needed information
 /// in the Deque
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct WobbleSample {
     /// raw position
     pub position: (f32, f32),
@@ -26,6 +70,36 @@ pub(crate) struct WobbleSample {
     pub time: f64,
 }
 
+/// A snapshot of a [StrokeModeler]'s in-flight stroke, captured by [StrokeModeler::save_state]
+/// and fed back in with [StrokeModeler::restore_state].
+///
+/// Covers every field that changes as a stroke progresses (the wobble smoother's window or
+/// EWMA estimate, the position modeler's spring state, the stylus state modeler's buffered
+/// samples, the Kalman predictor, and the last input seen) but not [ModelerParams]: restoring
+/// a snapshot never changes the modeler's configuration, only its in-progress stroke.
+///
+/// Feeding the same subsequent inputs after [StrokeModeler::restore_state] reproduces the same
+/// [ModelerResult]s as the uninterrupted run, so this can be used to checkpoint a long stroke,
+/// fork a speculative prediction branch and roll it back, or persist an interrupted stroke
+/// across a suspend/resume cycle.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModelerState {
+    wobble_deque: VecDeque<WobbleSample>,
+    wobble_weighted_pos_sum: (f32, f32),
+    wobble_duration_sum: f64,
+    wobble_distance_sum: f32,
+    wobble_ewma_pos: (f32, f32),
+    wobble_ewma_speed: f32,
+    wobble_ewma_last: Option<(f32, f32, f64)>,
+    position_modeler: Option<PositionModeler>,
+    last_event: Option<ModelerInput>,
+    last_corrected_event: Option<(f32, f32)>,
+    state_modeler: StateModeler,
+    kalman_predictor: Option<KalmanPredictor>,
+    element_index: usize,
+}
+
 /// This class models a stroke from a raw input stream. The modeling is performed in
 /// several stages
 /// - Wobble smoothing : dampens high-frequency noise from quantization error
@@ -50,12 +124,33 @@ pub struct StrokeModeler {
     pub(crate) wobble_duration_sum: f64,
     /// running distance sum
     pub(crate) wobble_distance_sum: f32,
+    /// exponentially-weighted moving average of position, used instead of [Self::wobble_deque]
+    /// when [ModelerParams::wobble_smoother_mode] is [crate::WobbleSmootherMode::Ewma]
+    pub(crate) wobble_ewma_pos: (f32, f32),
+    /// exponentially-weighted moving average of speed, used instead of
+    /// [Self::wobble_distance_sum]/[Self::wobble_duration_sum] when
+    /// [ModelerParams::wobble_smoother_mode] is [crate::WobbleSmootherMode::Ewma]
+    pub(crate) wobble_ewma_speed: f32,
+    /// raw position and time of the last event seen by the EWMA wobble smoother, used to
+    /// compute `dt` and the instantaneous speed for the next update
+    pub(crate) wobble_ewma_last: Option<(f32, f32, f64)>,
     // physical model for the stroke
     // only created on the first stroke
     pub(crate) position_modeler: Option<PositionModeler>,
     pub(crate) last_event: Option<ModelerInput>,
     pub(crate) last_corrected_event: Option<(f32, f32)>,
     pub(crate) state_modeler: StateModeler,
+    /// only created for the duration of a stroke, and only when
+    /// [ModelerParams::prediction] is set to [crate::PredictionParams::Kalman]
+    pub(crate) kalman_predictor: Option<KalmanPredictor>,
+    /// index of the next element to be submitted via [StrokeModeler::update]/
+    /// [StrokeModeler::update_to], for the `index` field of [ElementError]/[ElementOrderError].
+    /// Counts every element submitted to this modeler, not just the current stroke, so an
+    /// error message points at the same element whether or not earlier strokes have completed.
+    /// Elements rejected by [ModelerParams::strict_input_validation] are not counted.
+    pub(crate) element_index: usize,
+    /// the single stored checkpoint used by [StrokeModeler::save]/[StrokeModeler::restore]
+    pub(crate) checkpoint: Option<ModelerState>,
 }
 
 impl Default for StrokeModeler {
@@ -70,11 +165,20 @@ impl Default for StrokeModeler {
             wobble_weighted_pos_sum: (0.0, 0.0),
             wobble_duration_sum: 0.0,
             wobble_distance_sum: 0.0,
+            wobble_ewma_pos: (0.0, 0.0),
+            wobble_ewma_speed: 0.0,
+            wobble_ewma_last: None,
 
             last_event: None,
             last_corrected_event: None,
             position_modeler: None,
-            state_modeler: StateModeler::new(params.stylus_state_modeler_max_input_samples),
+            state_modeler: StateModeler::new(
+                params.stylus_state_modeler_max_input_samples,
+                params.stylus_state_config,
+            ),
+            kalman_predictor: None,
+            element_index: 0,
+            checkpoint: None,
         }
     }
 }
@@ -97,8 +201,17 @@ impl StrokeModeler {
             wobble_duration_sum: 0.0,
             wobble_weighted_pos_sum: (0.0, 0.0),
             wobble_distance_sum: 0.0,
+            wobble_ewma_pos: (0.0, 0.0),
+            wobble_ewma_speed: 0.0,
+            wobble_ewma_last: None,
             position_modeler: None,
-            state_modeler: StateModeler::new(params.stylus_state_modeler_max_input_samples),
+            state_modeler: StateModeler::new(
+                params.stylus_state_modeler_max_input_samples,
+                params.stylus_state_config,
+            ),
+            kalman_predictor: None,
+            element_index: 0,
+            checkpoint: None,
         })
     }
 
@@ -111,9 +224,16 @@ impl StrokeModeler {
         );
         self.wobble_duration_sum = 0.0;
         self.wobble_weighted_pos_sum = (0.0, 0.0);
+        self.wobble_ewma_pos = (0.0, 0.0);
+        self.wobble_ewma_speed = 0.0;
+        self.wobble_ewma_last = None;
         self.position_modeler = None;
-        self.state_modeler
-            .reset(self.params.stylus_state_modeler_max_input_samples);
+        self.state_modeler.reset(
+            self.params.stylus_state_modeler_max_input_samples,
+            self.params.stylus_state_config,
+        );
+        self.kalman_predictor = None;
+        self.checkpoint = None;
     }
 
     /// Clears any in-progress stroke, and re initialize the model with
@@ -127,101 +247,348 @@ impl StrokeModeler {
         self.wobble_duration_sum = 0.0;
         self.wobble_weighted_pos_sum = (0.0, 0.0);
         self.wobble_distance_sum = 0.0;
+        self.wobble_ewma_pos = (0.0, 0.0);
+        self.wobble_ewma_speed = 0.0;
+        self.wobble_ewma_last = None;
         self.position_modeler = None;
-        self.state_modeler
-            .reset(params.stylus_state_modeler_max_input_samples);
+        self.state_modeler.reset(
+            params.stylus_state_modeler_max_input_samples,
+            params.stylus_state_config,
+        );
+        self.kalman_predictor = None;
+        self.checkpoint = None;
+        self.params = params;
         Ok(())
     }
 
-    /// Updates the model with a raw input, and appends newly generated Results to the results vector.
+    /// Captures the full in-flight stroke state (wobble smoother, position modeler, stylus
+    /// state modeler, Kalman predictor and last input) as a [ModelerState] that can later be
+    /// fed back into [StrokeModeler::restore_state], on this or another [StrokeModeler]
+    /// constructed with the same [ModelerParams].
+    pub fn save_state(&self) -> ModelerState {
+        ModelerState {
+            wobble_deque: self.wobble_deque.clone(),
+            wobble_weighted_pos_sum: self.wobble_weighted_pos_sum,
+            wobble_duration_sum: self.wobble_duration_sum,
+            wobble_distance_sum: self.wobble_distance_sum,
+            wobble_ewma_pos: self.wobble_ewma_pos,
+            wobble_ewma_speed: self.wobble_ewma_speed,
+            wobble_ewma_last: self.wobble_ewma_last,
+            position_modeler: self.position_modeler.clone(),
+            last_event: self.last_event.clone(),
+            last_corrected_event: self.last_corrected_event,
+            state_modeler: self.state_modeler.clone(),
+            kalman_predictor: self.kalman_predictor.clone(),
+            element_index: self.element_index,
+        }
+    }
+
+    /// Restores an in-flight stroke previously captured by [StrokeModeler::save_state],
+    /// replacing the current in-progress stroke (if any) but leaving [ModelerParams]
+    /// untouched. Feeding the same subsequent inputs after this call reproduces the same
+    /// [ModelerResult]s as the uninterrupted run the snapshot was taken from.
+    pub fn restore_state(&mut self, state: ModelerState) {
+        self.wobble_deque = state.wobble_deque;
+        self.wobble_weighted_pos_sum = state.wobble_weighted_pos_sum;
+        self.wobble_duration_sum = state.wobble_duration_sum;
+        self.wobble_distance_sum = state.wobble_distance_sum;
+        self.wobble_ewma_pos = state.wobble_ewma_pos;
+        self.wobble_ewma_speed = state.wobble_ewma_speed;
+        self.wobble_ewma_last = state.wobble_ewma_last;
+        self.position_modeler = state.position_modeler;
+        self.last_event = state.last_event;
+        self.last_corrected_event = state.last_corrected_event;
+        self.state_modeler = state.state_modeler;
+        self.kalman_predictor = state.kalman_predictor;
+        self.element_index = state.element_index;
+    }
+
+    /// Captures the current in-flight stroke into a single checkpoint stored on this
+    /// [StrokeModeler], overwriting any checkpoint from a previous [StrokeModeler::save] call.
+    ///
+    /// Pairs with [StrokeModeler::restore]: a caller can `save()`, feed speculative `Move`
+    /// inputs to probe how the curve continues, then `restore()` to cheaply roll back to
+    /// exactly this point, without cloning the whole modeler on every frame. Internally this is
+    /// a thin wrapper over [StrokeModeler::save_state]; reach for that instead if more than one
+    /// checkpoint needs to be held at a time.
+    pub fn save(&mut self) {
+        self.checkpoint = Some(self.save_state());
+    }
+
+    /// Rewinds to the checkpoint captured by the most recent [StrokeModeler::save] call,
+    /// leaving [ModelerParams] untouched. Does nothing if [StrokeModeler::save] has not been
+    /// called (or not since the last [StrokeModeler::reset]/[StrokeModeler::reset_w_params]).
+    ///
+    /// The checkpoint itself is kept after restoring, so `restore()` can be called again to
+    /// probe another speculative branch from the same saved point.
+    pub fn restore(&mut self) {
+        if let Some(state) = self.checkpoint.clone() {
+            self.restore_state(state);
+        }
+    }
+
+    /// Updates the model with a raw input, returning the newly generated Results.
+    ///
+    /// A thin, allocating wrapper around [StrokeModeler::update_to] for callers that don't
+    /// need to reuse a buffer across calls; see it for the full behavior and error conditions.
+    pub fn update(&mut self, input: ModelerInput) -> Result<Vec<ModelerResult>, ModelerError> {
+        let mut out = Vec::new();
+        self.update_to(input, &mut out)?;
+        Ok(out)
+    }
+
+    /// [Scalar]-generic counterpart of [StrokeModeler::update], for callers whose raw input
+    /// events carry a different float representation than this crate's `f64`. Converts `pos`,
+    /// `time`, `pressure`, `tilt` and `orientation` to `f64` and delegates to
+    /// [StrokeModeler::update]; see it for the full behavior and error conditions.
+    pub fn update_scalar<F: Scalar>(
+        &mut self,
+        event_type: ModelerInputEventType,
+        pos: (F, F),
+        time: F,
+        pressure: F,
+        tilt: Option<F>,
+        orientation: Option<F>,
+    ) -> Result<Vec<ModelerResult>, ModelerError> {
+        self.update(ModelerInput {
+            event_type,
+            pos: (
+                pos.0.to_f64().unwrap_or_default(),
+                pos.1.to_f64().unwrap_or_default(),
+            ),
+            time: time.to_f64().unwrap_or_default(),
+            pressure: pressure.to_f64().unwrap_or_default(),
+            tilt: tilt.and_then(|t| t.to_f64()),
+            orientation: orientation.and_then(|o| o.to_f64()),
+        })
+    }
+
+    /// Updates the model with a raw input, and appends newly generated Results to `out`.
     /// Any previously generated Result values remain valid.
-    /// (This does not require that any previous results returned remain in the results vector, as it is
+    /// (This does not require that any previous results returned remain in `out`, as it is
     /// appended to without examining the existing contents)
     ///
-    /// If this does not return an error, results will contain at least one Result, and potentially
+    /// If this does not return an error, at least one Result is appended, and potentially
     /// more if the inputs are slower than the minimum output rate
     ///
-    /// for now rnote's wrapper codes verify that the input is not duplicated and time increases between strokes
-    /// This is not tested here, as we suppose that these things are verified beforehand
-    pub fn update(&mut self, input: ModelerInput) -> Result<Vec<ModelerResult>, String> {
+    /// Returns a [ModelerError] if the input is invalid : a `Down` event that isn't the first,
+    /// or that occurs while a stroke is already in progress
+    /// ([ElementOrderError::UnexpectedDown]), a `Move`/`Up` event before any `Down` event
+    /// ([ElementOrderError::UnexpectedMove]/[ElementOrderError::UnexpectedUp]), a time earlier
+    /// than the previous input's ([ElementError::NegativeTimeDelta]), a `Move` at the same time
+    /// as the previous input ([ElementError::Duplicate]; an `Up` at that time is accepted), or a
+    /// gap since the previous input
+    /// larger than [ModelerParams::sampling_max_time_gap] ([ElementError::TooFarApart]) — these
+    /// are always checked, and are reported through [ModelerError::Element]. When
+    /// [ModelerParams::strict_input_validation] is set (the default), a non-finite position,
+    /// time, pressure, tilt or orientation ([ModelerError::NonFiniteInput]), a negative
+    /// pressure ([ModelerError::NegativePressure]), or a pressure, tilt or orientation outside
+    /// its valid range ([ModelerError::OutOfRange]) are also rejected. Nothing is appended to
+    /// `out` on any of these input errors.
+    pub fn update_to(
+        &mut self,
+        input: ModelerInput,
+        out: &mut impl ResultSink,
+    ) -> Result<(), ModelerError> {
+        if self.params.strict_input_validation {
+            self.validate_input(&input)?;
+        }
+
+        let index = self.element_index;
+        self.element_index += 1;
+
         match input.event_type {
             ModelerInputEventType::Down => {
                 if self.last_event.is_some() {
-                    return Err(String::from("down event is not the first event or a down event occured after another one"));
+                    return Err(ModelerError::Element {
+                        src: ElementError::Order {
+                            src: ElementOrderError::UnexpectedDown {
+                                index,
+                                time: input.time,
+                            },
+                        },
+                    });
                 }
+                // a finished stroke's `Up` does not itself clear the wobble smoother state, so
+                // a new stroke must start from a clean window/EWMA rather than inheriting
+                // leftover samples from whatever stroke came before it
+                self.wobble_deque = VecDeque::with_capacity(
+                    (2.0 * self.params.sampling_min_output_rate
+                        * self.params.wobble_smoother_timeout) as usize,
+                );
+                self.wobble_weighted_pos_sum = (0.0, 0.0);
+                self.wobble_duration_sum = 0.0;
+                self.wobble_distance_sum = 0.0;
+                self.wobble_ewma_pos = (0.0, 0.0);
+                self.wobble_ewma_speed = 0.0;
+                self.wobble_ewma_last = None;
                 self.wobble_update(&input); // first event is "as is"
 
                 self.position_modeler = Some(PositionModeler::new(self.params, input.clone()));
 
                 self.last_event = Some(input.clone());
-                self.last_corrected_event = Some(input.pos);
-                self.state_modeler
-                    .reset(self.params.stylus_state_modeler_max_input_samples);
+                self.last_corrected_event = Some((input.pos.0 as f32, input.pos.1 as f32));
+                self.state_modeler.reset(
+                    self.params.stylus_state_modeler_max_input_samples,
+                    self.params.stylus_state_config,
+                );
                 self.state_modeler.update(input.clone());
-                Ok(vec![ModelerResult {
-                    pos: input.pos,
+                self.kalman_predictor = match self.params.prediction {
+                    PredictionParams::Kalman(kalman_params) => {
+                        // the predicted tail is stepped at the modeler's own output rate, so
+                        // the number of predicted points tracks `sampling_min_output_rate`
+                        // rather than a rate configured independently of it
+                        let kalman_params = KalmanPredictorParams {
+                            prediction_interval: 1.0 / self.params.sampling_min_output_rate,
+                            ..kalman_params
+                        };
+                        Some(KalmanPredictor::new(
+                            kalman_params,
+                            (input.pos.0 as f32, input.pos.1 as f32),
+                            input.time,
+                        ))
+                    }
+                    PredictionParams::Disabled | PredictionParams::StrokeEnd => None,
+                };
+                out.push_result(ModelerResult {
+                    pos: (input.pos.0 as f32, input.pos.1 as f32),
                     velocity: (0.0, 0.0),
                     acceleration: (0.0, 0.0),
                     time: input.time,
-                    pressure: input.pressure,
-                }])
+                    pressure: input.pressure as f32,
+                    tilt: input.tilt,
+                    orientation: input.orientation,
+                })?;
+                Ok(())
             }
             ModelerInputEventType::Move => {
                 // get the latest element
                 if self.last_event.is_none() {
-                    return Err(String::from("no Down event occurred before a Move event"));
+                    return Err(ModelerError::Element {
+                        src: ElementError::Order {
+                            src: ElementOrderError::UnexpectedMove {
+                                index,
+                                time: input.time,
+                            },
+                        },
+                    });
                 }
                 let latest_time = self.last_event.as_ref().unwrap().time;
                 let new_time = input.time;
+                if new_time < latest_time {
+                    return Err(ModelerError::Element {
+                        src: ElementError::NegativeTimeDelta {
+                            index,
+                            time: new_time,
+                            previous_time: latest_time,
+                        },
+                    });
+                }
+                if new_time == latest_time {
+                    return Err(ModelerError::Element {
+                        src: ElementError::Duplicate {
+                            index,
+                            time: new_time,
+                        },
+                    });
+                }
+                let gap = new_time - latest_time;
+                if gap > self.params.sampling_max_time_gap {
+                    return Err(ModelerError::Element {
+                        src: ElementError::TooFarApart {
+                            index,
+                            time: new_time,
+                            previous_time: latest_time,
+                            max_gap: self.params.sampling_max_time_gap,
+                        },
+                    });
+                }
                 self.state_modeler.update(input.clone());
 
-                // calculate the number of element to predict
-                let n_tsteps = (((new_time - latest_time) * self.params.sampling_min_output_rate)
-                    .ceil() as i32)
-                    .min(i32::MAX);
-
-                // this does not check for very large inputs
-                // this does not error if the number of steps is larger than
-                // [ModelParams::sampling_max_outputs_per_call]
+                // calculate the number of element to predict, capped so a pathological
+                // `delta_time` can't make this allocate an unbounded result vector
+                let n_tsteps = ((gap * self.params.sampling_min_output_rate).ceil() as i32)
+                    .min(self.params.sampling_max_outputs_per_call as i32);
 
                 let p_start = self.last_corrected_event.unwrap();
                 let p_end = self.wobble_update(&input);
                 // seems like speeds are way higher than normal speed encountered so no smoothing occurs here
 
-                let vec_out: Vec<ModelerResult> = self
+                for result in self
                     .position_modeler
                     .as_mut()
                     .unwrap()
                     .update_along_linear_path(p_start, latest_time, p_end, new_time, n_tsteps)
                     .into_iter()
-                    .map(|i| ModelerResult {
-                        pressure: self.state_modeler.query(i.pos),
-                        pos: i.pos,
-                        velocity: i.velocity,
-                        acceleration: i.acceleration,
-                        time: i.time,
+                    .map(|i| {
+                        let stylus_state = self.state_modeler.query(i.pos);
+                        ModelerResult {
+                            pos: i.pos,
+                            velocity: i.velocity,
+                            acceleration: i.acceleration,
+                            time: i.time,
+                            pressure: stylus_state.pressure,
+                            tilt: stylus_state.tilt,
+                            orientation: stylus_state.orientation,
+                        }
                     })
-                    .collect();
+                {
+                    out.push_result(result)?;
+                }
+
+                if let Some(kalman_predictor) = self.kalman_predictor.as_mut() {
+                    kalman_predictor
+                        .update((input.pos.0 as f32, input.pos.1 as f32), input.time);
+                }
 
                 // push the latest element (should we push everything we also interpolated as well ?)
                 self.last_event = Some(input.clone());
                 self.last_corrected_event = Some(p_end);
 
-                Ok(vec_out)
+                Ok(())
             }
             ModelerInputEventType::Up => {
                 // get the latest element
                 if self.last_event.is_none() {
-                    return Err(String::from("No event occured before an up event"));
+                    return Err(ModelerError::Element {
+                        src: ElementError::Order {
+                            src: ElementOrderError::UnexpectedUp {
+                                index,
+                                time: input.time,
+                            },
+                        },
+                    });
                 }
                 let latest_time = self.last_event.as_ref().unwrap().time;
                 let new_time = input.time;
+                if new_time < latest_time {
+                    return Err(ModelerError::Element {
+                        src: ElementError::NegativeTimeDelta {
+                            index,
+                            time: new_time,
+                            previous_time: latest_time,
+                        },
+                    });
+                }
+                // Note: an Up event is allowed to share its time with the previous Move,
+                // see the comment below on why the end-of-stroke fallback bumps the time itself.
+                let gap = new_time - latest_time;
+                if gap > self.params.sampling_max_time_gap {
+                    return Err(ModelerError::Element {
+                        src: ElementError::TooFarApart {
+                            index,
+                            time: new_time,
+                            previous_time: latest_time,
+                            max_gap: self.params.sampling_max_time_gap,
+                        },
+                    });
+                }
                 self.state_modeler.update(input.clone());
 
-                // calculate the number of element to predict
-                let n_tsteps = (((new_time - latest_time) * self.params.sampling_min_output_rate)
-                    .ceil() as i32)
-                    .min(i32::MAX);
+                // calculate the number of element to predict, capped so a pathological
+                // `delta_time` can't make this allocate an unbounded result vector
+                let n_tsteps = ((gap * self.params.sampling_min_output_rate).ceil() as i32)
+                    .min(self.params.sampling_max_outputs_per_call as i32);
 
                 let p_start = self.last_corrected_event.unwrap();
                 // the p_end is purposefully different from the original implementation
@@ -230,49 +597,65 @@ impl StrokeModeler {
                 // behavior between the predict on a Move and a Up
                 let p_end = self.wobble_update(&input);
 
-                let mut vec_out = Vec::<ModelerResult>::with_capacity(
+                out.reserve(
                     (n_tsteps as usize) + self.params.sampling_end_of_stroke_max_iterations,
                 );
+                let pre_len = out.len();
 
-                vec_out.extend(
-                    self.position_modeler
-                        .as_mut()
-                        .unwrap()
-                        .update_along_linear_path(p_start, latest_time, p_end, new_time, n_tsteps)
-                        .into_iter()
-                        .map(|i| ModelerResult {
-                            pressure: self.state_modeler.query(i.pos),
+                for result in self
+                    .position_modeler
+                    .as_mut()
+                    .unwrap()
+                    .update_along_linear_path(p_start, latest_time, p_end, new_time, n_tsteps)
+                    .into_iter()
+                    .map(|i| {
+                        let stylus_state = self.state_modeler.query(i.pos);
+                        ModelerResult {
                             pos: i.pos,
                             velocity: i.velocity,
                             time: i.time,
                             acceleration: i.acceleration,
-                        }),
-                );
+                            pressure: stylus_state.pressure,
+                            tilt: stylus_state.tilt,
+                            orientation: stylus_state.orientation,
+                        }
+                    })
+                {
+                    out.push_result(result)?;
+                }
 
                 // model the end of stroke
-                vec_out.extend(
-                    self.position_modeler
-                        .as_mut()
-                        .unwrap()
-                        .model_end_of_stroke(
-                            input.pos,
-                            1. / self.params.sampling_min_output_rate,
-                            self.params.sampling_end_of_stroke_max_iterations,
-                            self.params.sampling_end_of_stroke_stopping_distance,
-                        )
-                        .into_iter()
-                        .map(|i| ModelerResult {
-                            pressure: self.state_modeler.query(i.pos),
+                for result in self
+                    .position_modeler
+                    .as_mut()
+                    .unwrap()
+                    .model_end_of_stroke(
+                        (input.pos.0 as f32, input.pos.1 as f32),
+                        1. / self.params.sampling_min_output_rate,
+                        self.params.sampling_end_of_stroke_max_iterations,
+                        self.params.sampling_end_of_stroke_stopping_distance,
+                    )
+                    .into_iter()
+                    .map(|i| {
+                        let stylus_state = self.state_modeler.query(i.pos);
+                        ModelerResult {
                             pos: i.pos,
                             velocity: i.velocity,
                             acceleration: i.acceleration,
                             time: i.time,
-                        }),
-                );
+                            pressure: stylus_state.pressure,
+                            tilt: stylus_state.tilt,
+                            orientation: stylus_state.orientation,
+                        }
+                    })
+                {
+                    out.push_result(result)?;
+                }
 
-                if vec_out.is_empty() {
+                if out.len() == pre_len {
                     let state_pos = self.position_modeler.as_ref().unwrap().state.clone();
-                    vec_out.push(ModelerResult {
+                    let stylus_state = self.state_modeler.query(state_pos.pos);
+                    out.push_result(ModelerResult {
                         pos: state_pos.pos,
                         velocity: state_pos.velocity,
                         acceleration: state_pos.acceleration,
@@ -284,79 +667,258 @@ impl StrokeModeler {
                         // the status of the modeler changed, including the time by at least
                         // `1. / self.params.sampling_min_output_rate`
                         time: state_pos.time + 1. / self.params.sampling_min_output_rate,
-                        pressure: self.state_modeler.query(state_pos.pos),
-                    });
+                        pressure: stylus_state.pressure,
+                        tilt: stylus_state.tilt,
+                        orientation: stylus_state.orientation,
+                    })?;
+                }
+
+                if let Some(kalman_predictor) = self.kalman_predictor.as_mut() {
+                    kalman_predictor
+                        .update((input.pos.0 as f32, input.pos.1 as f32), input.time);
                 }
 
                 // remove the last event
                 self.last_event = None;
+                self.kalman_predictor = None;
+
+                Ok(())
+            }
+        }
+    }
 
-                Ok(vec_out)
+    /// Checks an input's position, time, pressure, tilt and orientation for `NaN`/infinite
+    /// values and out-of-range pressure/tilt/orientation, gated behind
+    /// [ModelerParams::strict_input_validation]. The ordering checks (reported through
+    /// [ModelerError::Element]) are a correctness requirement of the modeling math itself (an
+    /// unordered or duplicate timestamp would divide by a zero or negative duration) and stay
+    /// unconditional, performed inline in [StrokeModeler::update_to].
+    fn validate_input(&self, input: &ModelerInput) -> Result<(), ModelerError> {
+        if !input.pos.0.is_finite() || !input.pos.1.is_finite() || !input.time.is_finite() {
+            return Err(ModelerError::NonFiniteInput);
+        }
+        if !input.pressure.is_finite() {
+            return Err(ModelerError::NonFiniteInput);
+        }
+        if input.pressure < 0.0 {
+            return Err(ModelerError::NegativePressure);
+        }
+        if !input.pressure.is_finite() || input.pressure > 1.0 {
+            return Err(ModelerError::OutOfRange {
+                field: "pressure",
+                value: input.pressure,
+            });
+        }
+        if let Some(tilt) = input.tilt {
+            if !tilt.is_finite() {
+                return Err(ModelerError::NonFiniteInput);
+            }
+            if !(0.0..=std::f64::consts::FRAC_PI_2).contains(&tilt) {
+                return Err(ModelerError::OutOfRange {
+                    field: "tilt",
+                    value: tilt,
+                });
+            }
+        }
+        if let Some(orientation) = input.orientation {
+            if !orientation.is_finite() {
+                return Err(ModelerError::NonFiniteInput);
+            }
+            if !(0.0..std::f64::consts::TAU).contains(&orientation) {
+                return Err(ModelerError::OutOfRange {
+                    field: "orientation",
+                    value: orientation,
+                });
             }
         }
+        Ok(())
+    }
+
+    /// Like [StrokeModeler::update_to], but writes results into a caller-provided
+    /// [FixedResultBuffer] instead of a `Vec`, so a single `update` call never allocates.
+    /// Only available with the `fixed-buffer` feature. Drives [StrokeModeler::update_to]'s own
+    /// per-event/per-substep emission straight into `out` — there is no intermediate
+    /// `Vec<ModelerResult>` collected and thrown away in between. Returns
+    /// [ModelerError::BufferOverflow] as soon as the `N`th result would be exceeded; unlike
+    /// collecting into an unbounded `Vec` first, the results already written into `out` before
+    /// that point are not lost.
+    #[cfg(feature = "fixed-buffer")]
+    pub fn update_into<const N: usize>(
+        &mut self,
+        input: ModelerInput,
+        out: &mut FixedResultBuffer<N>,
+    ) -> Result<(), ModelerError> {
+        self.update_to(input, out)
     }
 
-    /// Models the given input prediction without changing the internal model state
+    /// Extrapolates the trailing pen trajectory beyond the last real input, returning the
+    /// speculative tail.
     ///
-    /// Returns an error if the model has not yet been initialized,
-    /// if there is no stroke in progress
+    /// A thin, allocating wrapper around [StrokeModeler::predict_to] for callers that don't
+    /// need to reuse a buffer across calls; see it for the full behavior and error conditions.
     pub fn predict(&mut self) -> Result<Vec<ModelerResult>, String> {
+        let mut out = Vec::new();
+        self.predict_to(&mut out)?;
+        Ok(out)
+    }
+
+    /// Extrapolates the trailing pen trajectory beyond the last real input, appending the
+    /// speculative tail to `out`, without changing the internal model state (repeated calls
+    /// are idempotent). The extrapolation strategy is selected by [ModelerParams::prediction] :
+    /// [PredictionParams::StrokeEnd] decays the current motion towards the last raw input the
+    /// same way the end-of-stroke catch-up does, while [PredictionParams::Kalman] projects the
+    /// filtered position/velocity/acceleration state forward at the configured sample interval.
+    ///
+    /// These results are speculative and distinct from the confirmed results returned by
+    /// [StrokeModeler::update]/[StrokeModeler::update_to] : callers should render them
+    /// separately (e.g. at lower opacity), and are expected to pass a scratch buffer they
+    /// clear themselves, rather than the buffer accumulating the confirmed stroke.
+    ///
+    /// Returns an error if the model has not yet been initialized,
+    /// if there is no stroke in progress. Nothing is appended to `out` on error.
+    pub fn predict_to(&mut self, out: &mut Vec<ModelerResult>) -> Result<(), String> {
         // for now return the latest element if it exists from the input
         if self.last_event.is_none() {
             // no data to predict from
-            Err(String::from("empty input events"))
-        } else {
-            // construct the prediction (model_end_of_stroke does not modify the position modeler)
-            let predict = self
-                .position_modeler
-                .as_mut()
-                .unwrap()
-                .model_end_of_stroke(
-                    self.last_event.as_ref().unwrap().pos,
-                    1. / self.params.sampling_min_output_rate,
-                    self.params.sampling_end_of_stroke_max_iterations,
-                    self.params.sampling_end_of_stroke_stopping_distance,
-                )
-                .into_iter()
-                .map(|i| ModelerResult {
-                    pos: i.pos,
-                    velocity: i.velocity,
-                    acceleration: i.acceleration,
-                    time: i.time,
-                    pressure: self.state_modeler.query(i.pos),
-                })
-                .collect();
-            Ok(predict)
+            return Err(String::from("empty input events"));
+        }
+
+        match self.params.prediction {
+            PredictionParams::Disabled => Ok(()),
+            PredictionParams::StrokeEnd => {
+                // construct the prediction (model_end_of_stroke does not modify the position modeler)
+                out.extend(
+                    self.position_modeler
+                        .as_mut()
+                        .unwrap()
+                        .model_end_of_stroke(
+                            {
+                                let pos = self.last_event.as_ref().unwrap().pos;
+                                (pos.0 as f32, pos.1 as f32)
+                            },
+                            1. / self.params.sampling_min_output_rate,
+                            self.params.sampling_end_of_stroke_max_iterations,
+                            self.params.sampling_end_of_stroke_stopping_distance,
+                        )
+                        .into_iter()
+                        .map(|i| {
+                            let stylus_state = self.state_modeler.query(i.pos);
+                            ModelerResult {
+                                pos: i.pos,
+                                velocity: i.velocity,
+                                acceleration: i.acceleration,
+                                time: i.time,
+                                pressure: stylus_state.pressure,
+                                tilt: stylus_state.tilt,
+                                orientation: stylus_state.orientation,
+                            }
+                        }),
+                );
+                Ok(())
+            }
+            PredictionParams::Kalman(_) => {
+                let predicted = self
+                    .kalman_predictor
+                    .as_ref()
+                    .map(KalmanPredictor::predict)
+                    .unwrap_or_default();
+
+                if predicted.is_empty() {
+                    // no tail (the filter isn't stable yet, or its confidence rounds to zero
+                    // samples): fall back to the last modeled state
+                    let state_pos = self.position_modeler.as_ref().unwrap().state.clone();
+                    let stylus_state = self.state_modeler.query(state_pos.pos);
+                    out.push(ModelerResult {
+                        pos: state_pos.pos,
+                        velocity: state_pos.velocity,
+                        acceleration: state_pos.acceleration,
+                        time: state_pos.time,
+                        pressure: stylus_state.pressure,
+                        tilt: stylus_state.tilt,
+                        orientation: stylus_state.orientation,
+                    });
+                    return Ok(());
+                }
+
+                // route the filter's raw projected positions through the position modeler so
+                // the predicted tail is smoothed by the same spring-mass-damper as the
+                // modeled stroke, instead of being handed to the caller as raw filter output
+                let smoothed = self
+                    .position_modeler
+                    .as_mut()
+                    .unwrap()
+                    .predict_through(&predicted);
+
+                out.extend(smoothed.into_iter().map(|i| {
+                    let stylus_state = self.state_modeler.query(i.pos);
+                    ModelerResult {
+                        pos: i.pos,
+                        velocity: i.velocity,
+                        acceleration: i.acceleration,
+                        time: i.time,
+                        pressure: stylus_state.pressure,
+                        tilt: stylus_state.tilt,
+                        orientation: stylus_state.orientation,
+                    }
+                }));
+                Ok(())
+            }
         }
     }
+
+    /// Like [StrokeModeler::predict_to], but clears `out` first instead of appending to it.
+    /// Unlike [StrokeModeler::update_to] (where every call's results are new and meant to
+    /// accumulate in the caller's buffer), each [StrokeModeler::predict_to] call recomputes the
+    /// same speculative tail from the current state, so a caller re-predicting every frame
+    /// wants the buffer replaced, not grown without bound; this spares such a caller the
+    /// `out.clear()` they would otherwise have to remember to do themselves.
+    pub fn predict_into(&mut self, out: &mut Vec<ModelerResult>) -> Result<(), String> {
+        out.clear();
+        self.predict_to(out)
+    }
+
     ///implements the wobble logic
     ///smoothes out the input position from high frequency noise
     ///uses a moving average of position and interpolating between this
     ///position and the raw position based on the speed.
     ///high speeds movements won't be smoothed but low speed will.
+    ///
+    /// dispatches to [Self::wobble_update_window] or [Self::wobble_update_ewma] depending on
+    /// [ModelerParams::wobble_smoother_mode]
     #[doc = include_str!("../docs/wobble.html")]
     fn wobble_update(&mut self, event: &ModelerInput) -> (f32, f32) {
+        match self.params.wobble_smoother_mode {
+            WobbleSmootherMode::Window => self.wobble_update_window(event),
+            WobbleSmootherMode::Ewma => self.wobble_update_ewma(event),
+        }
+    }
+
+    /// windowed-average implementation of wobble smoothing: keeps every sample within
+    /// [ModelerParams::wobble_smoother_timeout] of `event` in [Self::wobble_deque] and averages
+    /// over it. Memory use grows with the input rate.
+    fn wobble_update_window(&mut self, event: &ModelerInput) -> (f32, f32) {
+        let pos = (event.pos.0 as f32, event.pos.1 as f32);
         match self.wobble_deque.len() {
             0 => {
                 self.wobble_deque.push_back(WobbleSample {
-                    position: event.pos,
+                    position: pos,
                     weighted_position: (0.0, 0.0),
                     distance: 0.0,
                     duration: 0.0,
                     time: event.time,
                 });
-                event.pos
+                pos
             }
             _ => {
                 let last_el = self.wobble_deque.back().unwrap();
                 let duration = event.time - last_el.time;
-                let weighted_pos = (event.pos.0 * duration as f32, event.pos.1 * duration as f32);
-                let distance = ((event.pos.0 - last_el.position.0).powi(2)
-                    + (event.pos.1 - last_el.position.1).powi(2))
+                let weighted_pos = (pos.0 * duration as f32, pos.1 * duration as f32);
+                let distance = ((pos.0 - last_el.position.0).powi(2)
+                    + (pos.1 - last_el.position.1).powi(2))
                 .sqrt();
 
                 self.wobble_deque.push_back(WobbleSample {
-                    position: event.pos,
+                    position: pos,
                     weighted_position: weighted_pos,
                     distance,
                     duration,
@@ -383,15 +945,20 @@ impl StrokeModeler {
                 }
 
                 if self.wobble_duration_sum < 1e-12 {
-                    event.pos
+                    pos
                 } else {
                     // calulate the average position
 
-                    // weird f32 and f64 mix
-                    let avg_position = (
-                        self.wobble_weighted_pos_sum.0 / self.wobble_duration_sum as f32,
-                        self.wobble_weighted_pos_sum.1 / self.wobble_duration_sum as f32,
-                    );
+                    let avg_position = match self.params.wobble_kernel {
+                        // weird f32 and f64 mix
+                        WobbleKernel::Uniform => (
+                            self.wobble_weighted_pos_sum.0 / self.wobble_duration_sum as f32,
+                            self.wobble_weighted_pos_sum.1 / self.wobble_duration_sum as f32,
+                        ),
+                        WobbleKernel::Triangular | WobbleKernel::Gaussian => {
+                            self.wobble_kernel_weighted_position(event.time)
+                        }
+                    };
 
                     let avg_speed = self.wobble_distance_sum / self.wobble_duration_sum as f32;
                     let norm_value = normalize01_32(
@@ -400,13 +967,96 @@ impl StrokeModeler {
                         avg_speed,
                     );
                     (
-                        interp(avg_position.0, event.pos.0, norm_value),
-                        interp(avg_position.1, event.pos.1, norm_value),
+                        interp(avg_position.0, pos.0, norm_value),
+                        interp(avg_position.1, pos.1, norm_value),
                     )
                 }
             }
         }
     }
+
+    /// recomputes the windowed average position directly from [Self::wobble_deque], weighting
+    /// each sample's `duration` by [ModelerParams::wobble_kernel]'s rolloff based on its age
+    /// relative to `now`. This is used instead of the incremental [Self::wobble_weighted_pos_sum]
+    /// for any kernel other than [WobbleKernel::Uniform]: a non-uniform kernel's weights shift
+    /// on every call as `now` advances, unlike the uniform case where a sample's weight (its
+    /// `duration`) never changes once it is pushed.
+    fn wobble_kernel_weighted_position(&self, now: f64) -> (f32, f32) {
+        let timeout = self.params.wobble_smoother_timeout;
+        let sigma = timeout / 3.0;
+
+        let mut weighted_pos = (0.0f32, 0.0f32);
+        let mut weight_sum = 0.0f32;
+        for sample in &self.wobble_deque {
+            let age = (now - sample.time).abs();
+            let kernel_factor = match self.params.wobble_kernel {
+                WobbleKernel::Uniform => 1.0,
+                WobbleKernel::Triangular => (1.0 - age / timeout).max(0.0),
+                WobbleKernel::Gaussian => (-(age * age) / (2.0 * sigma * sigma)).exp(),
+            } as f32;
+            let weight = sample.duration as f32 * kernel_factor;
+            weighted_pos.0 += weight * sample.position.0;
+            weighted_pos.1 += weight * sample.position.1;
+            weight_sum += weight;
+        }
+
+        if weight_sum < 1e-12 {
+            self.wobble_deque.back().unwrap().position
+        } else {
+            (weighted_pos.0 / weight_sum, weighted_pos.1 / weight_sum)
+        }
+    }
+
+    /// exponentially-weighted-moving-average implementation of wobble smoothing: keeps a
+    /// single running estimate of position and speed in [Self::wobble_ewma_pos] /
+    /// [Self::wobble_ewma_speed] instead of a deque, decaying them on each event as
+    /// `ewma = ewma + alpha * (sample - ewma)`, with `alpha = 1 - exp(-dt / tau)` and `tau`
+    /// taken from [ModelerParams::wobble_smoother_timeout]. Constant memory regardless of
+    /// input rate, at the cost of a softer cutoff than the windowed average.
+    fn wobble_update_ewma(&mut self, event: &ModelerInput) -> (f32, f32) {
+        let pos = (event.pos.0 as f32, event.pos.1 as f32);
+        match self.wobble_ewma_last {
+            None => {
+                self.wobble_ewma_last = Some((pos.0, pos.1, event.time));
+                self.wobble_ewma_pos = pos;
+                self.wobble_ewma_speed = 0.0;
+                pos
+            }
+            Some((last_x, last_y, last_time)) => {
+                let dt = event.time - last_time;
+                let distance = ((pos.0 - last_x).powi(2) + (pos.1 - last_y).powi(2)).sqrt();
+                let instant_speed = if dt > 1e-12 {
+                    distance / dt as f32
+                } else {
+                    0.0
+                };
+
+                let tau = self.params.wobble_smoother_timeout;
+                let alpha = if tau > 1e-12 {
+                    1.0 - (-dt / tau).exp()
+                } else {
+                    1.0
+                } as f32;
+
+                self.wobble_ewma_pos = (
+                    self.wobble_ewma_pos.0 + alpha * (pos.0 - self.wobble_ewma_pos.0),
+                    self.wobble_ewma_pos.1 + alpha * (pos.1 - self.wobble_ewma_pos.1),
+                );
+                self.wobble_ewma_speed += alpha * (instant_speed - self.wobble_ewma_speed);
+                self.wobble_ewma_last = Some((pos.0, pos.1, event.time));
+
+                let norm_value = normalize01_32(
+                    self.params.wobble_smoother_speed_floor,
+                    self.params.wobble_smoother_speed_ceiling,
+                    self.wobble_ewma_speed,
+                );
+                (
+                    interp(self.wobble_ewma_pos.0, pos.0, norm_value),
+                    interp(self.wobble_ewma_pos.1, pos.1, norm_value),
+                )
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -434,6 +1084,9 @@ mod tests {
             pos: (3., 4.),
             time: 1.0,
             pressure: 0.0,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(util_compare_floats(
             new_modeler.wobble_update(&ModelerInput {
@@ -441,6 +1094,9 @@ mod tests {
                 pos: (3.016, 4.),
                 time: 1.016,
                 pressure: 0.0,
+
+                tilt: None,
+                orientation: None,
             }),
             (3.016, 4.)
         ));
@@ -450,6 +1106,9 @@ mod tests {
                 pos: (3.032, 4.),
                 time: 1.032,
                 pressure: 0.0,
+
+                tilt: None,
+                orientation: None,
             }),
             (3.024, 4.)
         ));
@@ -459,6 +1118,9 @@ mod tests {
                 pos: (3.048, 4.),
                 time: 1.048,
                 pressure: 0.0,
+
+                tilt: None,
+                orientation: None,
             }),
             (3.032, 4.)
         ));
@@ -468,63 +1130,274 @@ mod tests {
                 pos: (3.064, 4.),
                 time: 1.064,
                 pressure: 0.0,
+
+                tilt: None,
+                orientation: None,
             }),
             (3.048, 4.)
         ));
     }
 
     #[test]
-    fn test_wobble_zigzag_slow() {
-        // need to create a StrokeModeler
-        let mut new_modeler = StrokeModeler::default();
-        new_modeler.wobble_update(&ModelerInput {
+    fn ewma_wobble_smoother_smooths_a_slow_steady_line() {
+        // same steady, slow-speed line as `test_wobble_smoother_line`, but with
+        // `WobbleSmootherMode::Ewma` instead of the default windowed average
+        let params = ModelerParamsBuilder::new()
+            .wobble_smoother_mode(WobbleSmootherMode::Ewma)
+            .build()
+            .unwrap();
+        let mut modeler = StrokeModeler::new(params).unwrap();
+        modeler.wobble_update(&ModelerInput {
             event_type: ModelerInputEventType::Down,
-            pos: (1., 2.),
-            time: 5.0,
+            pos: (3., 4.),
+            time: 1.0,
             pressure: 0.0,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(util_compare_floats(
-            new_modeler.wobble_update(&ModelerInput {
+            modeler.wobble_update(&ModelerInput {
                 event_type: ModelerInputEventType::Move,
-                pos: (1.016, 2.),
-                time: 5.016,
+                pos: (3.016, 4.),
+                time: 1.016,
                 pressure: 0.0,
+
+                tilt: None,
+                orientation: None,
             }),
-            (1.016, 2.0)
+            (3.0052748, 4.0)
         ));
         assert!(util_compare_floats(
-            new_modeler.wobble_update(&ModelerInput {
+            modeler.wobble_update(&ModelerInput {
                 event_type: ModelerInputEventType::Move,
-                pos: (1.016, 2.016),
-                time: 5.032,
+                pos: (3.032, 4.),
+                time: 1.032,
                 pressure: 0.0,
+
+                tilt: None,
+                orientation: None,
             }),
-            (1.016, 2.008)
+            (3.0140856, 4.0)
         ));
         assert!(util_compare_floats(
-            new_modeler.wobble_update(&ModelerInput {
+            modeler.wobble_update(&ModelerInput {
                 event_type: ModelerInputEventType::Move,
-                pos: (1.032, 2.016),
-                time: 5.048,
+                pos: (3.048, 4.),
+                time: 1.048,
                 pressure: 0.0,
+
+                tilt: None,
+                orientation: None,
             }),
-            (1.02133, 2.01067)
+            (3.0252665, 4.0)
         ));
+    }
+
+    #[test]
+    fn reset_clears_ewma_wobble_smoother_state() {
+        let params = ModelerParamsBuilder::new()
+            .wobble_smoother_mode(WobbleSmootherMode::Ewma)
+            .build()
+            .unwrap();
+        let mut modeler = StrokeModeler::new(params).unwrap();
+        modeler.wobble_update(&ModelerInput {
+            event_type: ModelerInputEventType::Down,
+            pos: (3., 4.),
+            time: 1.0,
+            pressure: 0.0,
+
+            tilt: None,
+            orientation: None,
+        });
+        modeler.wobble_update(&ModelerInput {
+            event_type: ModelerInputEventType::Move,
+            pos: (3.016, 4.),
+            time: 1.016,
+            pressure: 0.0,
+
+            tilt: None,
+            orientation: None,
+        });
+        assert!(modeler.wobble_ewma_last.is_some());
+
+        modeler.reset();
+        assert!(modeler.wobble_ewma_last.is_none());
+        assert_eq!(modeler.wobble_ewma_pos, (0.0, 0.0));
+        assert_eq!(modeler.wobble_ewma_speed, 0.0);
+
+        // after a reset, the next event is treated as the first of a new stroke, so it comes
+        // back out unsmoothed regardless of how the previous stroke ended
         assert!(util_compare_floats(
-            new_modeler.wobble_update(&ModelerInput {
-                event_type: ModelerInputEventType::Move,
-                pos: (1.032, 2.032),
-                time: 5.064,
+            modeler.wobble_update(&ModelerInput {
+                event_type: ModelerInputEventType::Down,
+                pos: (5., 6.),
+                time: 2.0,
                 pressure: 0.0,
+
+                tilt: None,
+                orientation: None,
             }),
-            (1.0266667, 2.0213333)
+            (5., 6.)
         ));
-        assert!(util_compare_floats(
-            new_modeler.wobble_update(&ModelerInput {
-                event_type: ModelerInputEventType::Move,
-                pos: (1.048, 2.032),
+    }
+
+    #[test]
+    fn wobble_kernel_uniform_matches_the_default() {
+        // WobbleKernel::Uniform is ModelerParams::suggested()'s default, so building with it
+        // explicitly must reproduce the same output as not touching wobble_kernel at all
+        let mut default_modeler = StrokeModeler::default();
+        let explicit_params = ModelerParamsBuilder::new()
+            .wobble_kernel(WobbleKernel::Uniform)
+            .build()
+            .unwrap();
+        let mut explicit_modeler = StrokeModeler::new(explicit_params).unwrap();
+
+        for (i, pos) in [(1., 2.), (1.016, 2.0), (1.032, 2.0), (1.048, 2.0)]
+            .into_iter()
+            .enumerate()
+        {
+            let input = ModelerInput {
+                event_type: if i == 0 {
+                    ModelerInputEventType::Down
+                } else {
+                    ModelerInputEventType::Move
+                },
+                pos,
+                time: 5.0 + i as f64 * 0.016,
+                pressure: 0.0,
+
+                tilt: None,
+                orientation: None,
+            };
+            assert!(util_compare_floats(
+                default_modeler.wobble_update(&input.clone()),
+                explicit_modeler.wobble_update(&input)
+            ));
+        }
+    }
+
+    #[test]
+    fn wobble_kernel_triangular_and_gaussian_favor_recent_samples_over_the_uniform_average() {
+        // a slow, steadily-advancing line: speed stays well under the default
+        // wobble_smoother_speed_floor (1.31), so the smoothed output is the windowed average
+        // position verbatim (the speed-based blend fully favors the smoothed estimate)
+        let positions = [
+            (0.00, 0.0),
+            (0.01, 0.0),
+            (0.02, 0.0),
+            (0.03, 0.0),
+            (0.04, 0.0),
+            (0.05, 0.0),
+        ];
+
+        let run = |kernel: WobbleKernel| -> (f32, f32) {
+            let params = ModelerParamsBuilder::new()
+                .wobble_kernel(kernel)
+                .build()
+                .unwrap();
+            let mut modeler = StrokeModeler::new(params).unwrap();
+            let mut last = (0.0, 0.0);
+            for (i, pos) in positions.into_iter().enumerate() {
+                let input = ModelerInput {
+                    event_type: if i == 0 {
+                        ModelerInputEventType::Down
+                    } else {
+                        ModelerInputEventType::Move
+                    },
+                    pos,
+                    time: i as f64 * 0.01,
+                    pressure: 0.0,
+
+                    tilt: None,
+                    orientation: None,
+                };
+                last = modeler.wobble_update(&input);
+            }
+            last
+        };
+
+        let uniform = run(WobbleKernel::Uniform);
+        let triangular = run(WobbleKernel::Triangular);
+        let gaussian = run(WobbleKernel::Gaussian);
+
+        // the line is monotonically advancing in x, so de-emphasizing stale (small-x)
+        // samples should pull the smoothed x closer to the latest raw position (0.05) than
+        // the flat box average does
+        assert!(triangular.0 > uniform.0);
+        assert!(gaussian.0 > uniform.0);
+    }
+
+    #[test]
+    fn test_wobble_zigzag_slow() {
+        // need to create a StrokeModeler
+        let mut new_modeler = StrokeModeler::default();
+        new_modeler.wobble_update(&ModelerInput {
+            event_type: ModelerInputEventType::Down,
+            pos: (1., 2.),
+            time: 5.0,
+            pressure: 0.0,
+
+            tilt: None,
+            orientation: None,
+        });
+        assert!(util_compare_floats(
+            new_modeler.wobble_update(&ModelerInput {
+                event_type: ModelerInputEventType::Move,
+                pos: (1.016, 2.),
+                time: 5.016,
+                pressure: 0.0,
+
+                tilt: None,
+                orientation: None,
+            }),
+            (1.016, 2.0)
+        ));
+        assert!(util_compare_floats(
+            new_modeler.wobble_update(&ModelerInput {
+                event_type: ModelerInputEventType::Move,
+                pos: (1.016, 2.016),
+                time: 5.032,
+                pressure: 0.0,
+
+                tilt: None,
+                orientation: None,
+            }),
+            (1.016, 2.008)
+        ));
+        assert!(util_compare_floats(
+            new_modeler.wobble_update(&ModelerInput {
+                event_type: ModelerInputEventType::Move,
+                pos: (1.032, 2.016),
+                time: 5.048,
+                pressure: 0.0,
+
+                tilt: None,
+                orientation: None,
+            }),
+            (1.02133, 2.01067)
+        ));
+        assert!(util_compare_floats(
+            new_modeler.wobble_update(&ModelerInput {
+                event_type: ModelerInputEventType::Move,
+                pos: (1.032, 2.032),
+                time: 5.064,
+                pressure: 0.0,
+
+                tilt: None,
+                orientation: None,
+            }),
+            (1.0266667, 2.0213333)
+        ));
+        assert!(util_compare_floats(
+            new_modeler.wobble_update(&ModelerInput {
+                event_type: ModelerInputEventType::Move,
+                pos: (1.048, 2.032),
                 time: 5.080,
                 pressure: 0.0,
+
+                tilt: None,
+                orientation: None,
             }),
             (1.0373333, 2.0266667)
         ));
@@ -534,6 +1407,9 @@ mod tests {
                 pos: (1.048, 2.048),
                 time: 5.096,
                 pressure: 0.0,
+
+                tilt: None,
+                orientation: None,
             }),
             (1.0426667, 2.0373333)
         ));
@@ -548,6 +1424,9 @@ mod tests {
                 pos: (7., 3.024),
                 time: 8.016,
                 pressure: 0.0,
+
+                tilt: None,
+                orientation: None,
             }),
             (7.0, 3.024)
         ));
@@ -557,6 +1436,9 @@ mod tests {
                 pos: (7.024, 3.024),
                 time: 8.032,
                 pressure: 0.0,
+
+                tilt: None,
+                orientation: None,
             }),
             (7.024, 3.024)
         ));
@@ -566,6 +1448,9 @@ mod tests {
                 pos: (7.024, 3.048),
                 time: 8.048,
                 pressure: 0.0,
+
+                tilt: None,
+                orientation: None,
             }),
             (7.024, 3.048)
         ));
@@ -575,6 +1460,9 @@ mod tests {
                 pos: (7.048, 3.048),
                 time: 8.064,
                 pressure: 0.0,
+
+                tilt: None,
+                orientation: None,
             }),
             (7.048, 3.048)
         ));
@@ -590,48 +1478,72 @@ mod tests {
                 pos: (0.0, 0.0),
                 time: 0.0,
                 pressure: 0.1,
+
+                tilt: None,
+                orientation: None,
             },
             ModelerInput {
                 event_type: ModelerInputEventType::Move,
                 pos: (1.0, 0.0),
                 time: 0.02,
                 pressure: 0.3,
+
+                tilt: None,
+                orientation: None,
             },
             ModelerInput {
                 event_type: ModelerInputEventType::Move,
                 pos: (2.0, 0.0),
                 time: 0.04,
                 pressure: 0.5,
+
+                tilt: None,
+                orientation: None,
             },
             ModelerInput {
                 event_type: ModelerInputEventType::Move,
                 pos: (2.5, 1.0),
                 time: 0.06,
                 pressure: 0.8,
+
+                tilt: None,
+                orientation: None,
             },
             ModelerInput {
                 event_type: ModelerInputEventType::Move,
                 pos: (3.0, 1.5),
                 time: 0.12,
                 pressure: 0.9,
+
+                tilt: None,
+                orientation: None,
             },
             ModelerInput {
                 event_type: ModelerInputEventType::Move,
                 pos: (4.0, 2.0),
                 time: 0.13,
                 pressure: 0.8,
+
+                tilt: None,
+                orientation: None,
             },
             ModelerInput {
                 event_type: ModelerInputEventType::Move,
                 pos: (3.8, 2.1),
                 time: 0.14,
                 pressure: 0.7,
+
+                tilt: None,
+                orientation: None,
             },
             ModelerInput {
                 event_type: ModelerInputEventType::Up,
                 pos: (3.5, 2.0),
                 time: 0.14,
                 pressure: 0.2,
+
+                tilt: None,
+                orientation: None,
             },
         ];
 
@@ -656,11 +1568,317 @@ mod tests {
                 event_type: ModelerInputEventType::Down,
                 time: 2.0,
                 pressure: 1.0,
+
+                tilt: None,
+                orientation: None,
             })
             .unwrap();
         assert_eq!(engine.predict().unwrap().len(), 0);
     }
 
+    fn util_dist(a: (f32, f32), b: (f32, f32)) -> f32 {
+        ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
+    }
+
+    #[test]
+    fn stroke_end_prediction_decays_toward_last_anchor() {
+        // the default prediction strategy is PredictionParams::StrokeEnd : once the pen
+        // stops moving, predict() should extrapolate a trajectory that settles towards the
+        // last raw input instead of shooting past it
+        let mut engine = StrokeModeler::default();
+        engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Down,
+                pos: (0.0, 0.0),
+                time: 0.0,
+                pressure: 1.0,
+                tilt: None,
+                orientation: None,
+            })
+            .unwrap();
+        engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Move,
+                pos: (5.0, 0.0),
+                time: 1. / 30.,
+                pressure: 1.0,
+                tilt: None,
+                orientation: None,
+            })
+            .unwrap();
+
+        let prediction = engine.predict().unwrap();
+        assert!(!prediction.is_empty());
+        let first_gap = util_dist(prediction.first().unwrap().pos, (5.0, 0.0));
+        let last_gap = util_dist(prediction.last().unwrap().pos, (5.0, 0.0));
+        assert!(last_gap <= first_gap);
+    }
+
+    #[test]
+    fn predict_is_idempotent_and_does_not_mutate_state() {
+        // predict() is meant to be called every frame to redraw a speculative tail, and
+        // discarded on the next real update : it must not leave any trace on the modeler
+        let mut engine = StrokeModeler::default();
+        engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Down,
+                pos: (0.0, 0.0),
+                time: 0.0,
+                pressure: 1.0,
+                tilt: None,
+                orientation: None,
+            })
+            .unwrap();
+        engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Move,
+                pos: (5.0, 0.0),
+                time: 1. / 30.,
+                pressure: 1.0,
+                tilt: None,
+                orientation: None,
+            })
+            .unwrap();
+
+        let first_call = engine.predict().unwrap();
+        let second_call = engine.predict().unwrap();
+        assert!(compare_results(first_call, second_call));
+    }
+
+    #[test]
+    fn update_to_appends_without_clearing_the_caller_buffer() {
+        let mut engine = StrokeModeler::default();
+        let mut results = Vec::new();
+        // a sentinel the real results must not clobber
+        results.push(ModelerResult::default());
+
+        engine
+            .update_to(
+                ModelerInput {
+                    event_type: ModelerInputEventType::Down,
+                    pos: (0.0, 0.0),
+                    time: 0.0,
+                    pressure: 1.0,
+                    tilt: None,
+                    orientation: None,
+                },
+                &mut results,
+            )
+            .unwrap();
+        assert_eq!(results.len(), 2);
+
+        engine
+            .update_to(
+                ModelerInput {
+                    event_type: ModelerInputEventType::Move,
+                    pos: (5.0, 0.0),
+                    time: 1. / 30.,
+                    pressure: 1.0,
+                    tilt: None,
+                    orientation: None,
+                },
+                &mut results,
+            )
+            .unwrap();
+        assert!(results.len() > 2);
+        assert_eq!(results.first().unwrap(), &ModelerResult::default());
+    }
+
+    #[test]
+    #[cfg(feature = "fixed-buffer")]
+    fn update_into_writes_straight_into_the_fixed_buffer_without_an_intermediate_vec() {
+        let mut engine = StrokeModeler::default();
+        let mut via_vec = Vec::new();
+        engine
+            .update_to(
+                ModelerInput {
+                    event_type: ModelerInputEventType::Down,
+                    pos: (0.0, 0.0),
+                    time: 0.0,
+                    pressure: 1.0,
+                    tilt: None,
+                    orientation: None,
+                },
+                &mut via_vec,
+            )
+            .unwrap();
+
+        let mut engine = StrokeModeler::default();
+        let mut via_fixed = FixedResultBuffer::<4>::new();
+        engine
+            .update_into(
+                ModelerInput {
+                    event_type: ModelerInputEventType::Down,
+                    pos: (0.0, 0.0),
+                    time: 0.0,
+                    pressure: 1.0,
+                    tilt: None,
+                    orientation: None,
+                },
+                &mut via_fixed,
+            )
+            .unwrap();
+
+        assert_eq!(via_fixed.as_slice(), via_vec.as_slice());
+    }
+
+    #[test]
+    #[cfg(feature = "fixed-buffer")]
+    fn update_into_reports_overflow_without_losing_the_results_already_written() {
+        let mut engine = StrokeModeler::default();
+        // capacity 1 : the Down event's single result fits, but the Move below produces more
+        // than one result and must overflow rather than silently drop them
+        let mut out = FixedResultBuffer::<1>::new();
+        engine
+            .update_into(
+                ModelerInput {
+                    event_type: ModelerInputEventType::Down,
+                    pos: (0.0, 0.0),
+                    time: 0.0,
+                    pressure: 1.0,
+                    tilt: None,
+                    orientation: None,
+                },
+                &mut out,
+            )
+            .unwrap();
+        assert_eq!(out.len(), 1);
+
+        let err = engine
+            .update_into(
+                ModelerInput {
+                    event_type: ModelerInputEventType::Move,
+                    pos: (5.0, 0.0),
+                    time: 1. / 30.,
+                    pressure: 1.0,
+                    tilt: None,
+                    orientation: None,
+                },
+                &mut out,
+            )
+            .unwrap_err();
+        assert!(matches!(err, ModelerError::BufferOverflow { capacity: 1 }));
+        // the result written before capacity was hit is still there, not discarded
+        assert_eq!(out.len(), 1);
+    }
+
+    #[test]
+    fn predict_to_matches_predict_and_appends_to_the_caller_buffer() {
+        let mut engine = StrokeModeler::default();
+        engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Down,
+                pos: (0.0, 0.0),
+                time: 0.0,
+                pressure: 1.0,
+                tilt: None,
+                orientation: None,
+            })
+            .unwrap();
+        engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Move,
+                pos: (5.0, 0.0),
+                time: 1. / 30.,
+                pressure: 1.0,
+                tilt: None,
+                orientation: None,
+            })
+            .unwrap();
+
+        let via_predict = engine.predict().unwrap();
+
+        let mut scratch = Vec::new();
+        engine.predict_to(&mut scratch).unwrap();
+        assert!(compare_results(via_predict, scratch.clone()));
+
+        // a second call appends on top of whatever the caller already had in the scratch buffer
+        let len_after_first_call = scratch.len();
+        engine.predict_to(&mut scratch).unwrap();
+        assert_eq!(scratch.len(), 2 * len_after_first_call);
+    }
+
+    #[test]
+    fn predict_into_replaces_the_caller_buffer_instead_of_appending() {
+        let mut engine = StrokeModeler::default();
+        engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Down,
+                pos: (0.0, 0.0),
+                time: 0.0,
+                pressure: 1.0,
+                tilt: None,
+                orientation: None,
+            })
+            .unwrap();
+        engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Move,
+                pos: (5.0, 0.0),
+                time: 1. / 30.,
+                pressure: 1.0,
+                tilt: None,
+                orientation: None,
+            })
+            .unwrap();
+
+        // pre-seed the buffer with unrelated leftovers from a previous frame
+        let mut scratch = vec![ModelerResult::default(); 3];
+        engine.predict_into(&mut scratch).unwrap();
+
+        let via_predict = engine.predict().unwrap();
+        assert!(compare_results(via_predict, scratch.clone()));
+
+        // a second call still only holds one frame's worth of results, not two
+        let len_after_first_call = scratch.len();
+        engine.predict_into(&mut scratch).unwrap();
+        assert_eq!(scratch.len(), len_after_first_call);
+    }
+
+    #[test]
+    fn kalman_prediction_is_stepped_at_the_modeler_sampling_rate_not_the_configured_one() {
+        // the `KalmanPredictorParams::prediction_interval` below is deliberately far from
+        // `1. / sampling_min_output_rate`, to confirm the predicted tail is stepped at the
+        // modeler's own output rate rather than a rate configured independently of it
+        let params = ModelerParams {
+            sampling_min_output_rate: 180.0,
+            prediction: PredictionParams::Kalman(KalmanPredictorParams {
+                min_stable_iteration: 1,
+                prediction_interval: 1.0,
+                ..KalmanPredictorParams::suggested()
+            }),
+            ..ModelerParams::suggested()
+        };
+        let mut engine = StrokeModeler::new(params).unwrap();
+        engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Down,
+                pos: (0.0, 0.0),
+                time: 0.0,
+                pressure: 1.0,
+                tilt: None,
+                orientation: None,
+            })
+            .unwrap();
+        for i in 1..=3 {
+            engine
+                .update(ModelerInput {
+                    event_type: ModelerInputEventType::Move,
+                    pos: (i as f64, 0.0),
+                    time: i as f64 / 180.,
+                    pressure: 1.0,
+                    tilt: None,
+                    orientation: None,
+                })
+                .unwrap();
+        }
+
+        let predicted = engine.predict().unwrap();
+        assert!(predicted.len() > 1);
+        let step = predicted[1].time - predicted[0].time;
+        assert!(approx::abs_diff_eq!(step, 1.0 / 180.0, epsilon = 1e-9));
+    }
+
     // tests for the stroke modeler
     #[test]
     fn input_rate_slower() {
@@ -678,6 +1896,9 @@ mod tests {
             pos: (3., 4.),
             time: time,
             pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(first_iter.is_ok());
         assert!(compare_results(
@@ -698,123 +1919,155 @@ mod tests {
                     event_type: ModelerInputEventType::Move,
                     pos: (3.2, 4.2),
                     time: time,
-                    pressure: 1.0
+                    pressure: 1.0,
+                    tilt: None,
+                    orientation: None,
                 })
                 .unwrap(),
             vec![
-                ModelerResult {
-                    pos: (3.0019, 4.0019),
-                    velocity: (0.4007, 0.4007),
-                    acceleration: (84.1557, 84.1564),
-                    time: 0.0048,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.0069, 4.0069),
-                    velocity: (1.0381, 1.0381),
-                    acceleration: (133.8378, 133.8369),
-                    time: 0.0095,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.0154, 4.0154),
-                    velocity: (1.7883, 1.7883),
-                    acceleration: (157.5465, 157.5459),
-                    time: 0.0143,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.0276, 4.0276),
-                    velocity: (2.5626, 2.5626),
-                    acceleration: (162.6039, 162.6021),
-                    time: 0.0190,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.0433, 4.0433),
-                    velocity: (3.3010, 3.3010),
-                    acceleration: (155.0670, 155.0666),
-                    time: 0.0238,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.0622, 4.0622),
-                    velocity: (3.9665, 3.9665),
-                    acceleration: (139.7575, 139.7564),
-                    time: 0.0286,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.0838, 4.0838),
-                    velocity: (4.5397, 4.5397),
-                    acceleration: (120.3618, 120.3625),
-                    time: 0.0333,
-                    pressure: 1.0
-                }
-            ]
+                            ModelerResult {
+                                pos: (3.0019, 4.0019),
+                                velocity: (0.4007, 0.4007),
+                                acceleration: (84.1557, 84.1564),
+                                time: 0.0048,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.0069, 4.0069),
+                                velocity: (1.0381, 1.0381),
+                                acceleration: (133.8378, 133.8369),
+                                time: 0.0095,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.0154, 4.0154),
+                                velocity: (1.7883, 1.7883),
+                                acceleration: (157.5465, 157.5459),
+                                time: 0.0143,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.0276, 4.0276),
+                                velocity: (2.5626, 2.5626),
+                                acceleration: (162.6039, 162.6021),
+                                time: 0.0190,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.0433, 4.0433),
+                                velocity: (3.3010, 3.3010),
+                                acceleration: (155.0670, 155.0666),
+                                time: 0.0238,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.0622, 4.0622),
+                                velocity: (3.9665, 3.9665),
+                                acceleration: (139.7575, 139.7564),
+                                time: 0.0286,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.0838, 4.0838),
+                                velocity: (4.5397, 4.5397),
+                                acceleration: (120.3618, 120.3625),
+                                time: 0.0333,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            }
+                        ]
         ));
 
         assert!(engine.predict().is_ok());
         assert!(compare_results(
             engine.predict().unwrap(),
             vec![
-                ModelerResult {
-                    pos: (3.1095, 4.1095),
-                    velocity: (4.6253, 4.6253),
-                    acceleration: (15.4218, 15.4223),
-                    time: 0.0389,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.1331, 4.1331),
-                    velocity: (4.2563, 4.2563),
-                    acceleration: (-66.4341, -66.4339),
-                    time: 0.0444,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.1534, 4.1534),
-                    velocity: (3.6479, 3.6479),
-                    acceleration: (-109.5083, -109.5081),
-                    time: 0.0500,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.1698, 4.1698),
-                    velocity: (2.9512, 2.9512),
-                    acceleration: (-125.3978, -125.3976),
-                    time: 0.0556,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.1824, 4.1824),
-                    velocity: (2.2649, 2.2649),
-                    acceleration: (-123.5318, -123.5310),
-                    time: 0.0611,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.1915, 4.1915),
-                    velocity: (1.6473, 1.6473),
-                    acceleration: (-111.1818, -111.1806),
-                    time: 0.0667,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.1978, 4.1978),
-                    velocity: (1.1269, 1.1269),
-                    acceleration: (-93.6643, -93.6636),
-                    time: 0.0722,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.1992, 4.1992),
-                    velocity: (1.0232, 1.0232),
-                    acceleration: (-74.6390, -74.6392),
-                    time: 0.0736,
-                    pressure: 1.0
-                }
-            ]
+                            ModelerResult {
+                                pos: (3.1095, 4.1095),
+                                velocity: (4.6253, 4.6253),
+                                acceleration: (15.4218, 15.4223),
+                                time: 0.0389,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.1331, 4.1331),
+                                velocity: (4.2563, 4.2563),
+                                acceleration: (-66.4341, -66.4339),
+                                time: 0.0444,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.1534, 4.1534),
+                                velocity: (3.6479, 3.6479),
+                                acceleration: (-109.5083, -109.5081),
+                                time: 0.0500,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.1698, 4.1698),
+                                velocity: (2.9512, 2.9512),
+                                acceleration: (-125.3978, -125.3976),
+                                time: 0.0556,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.1824, 4.1824),
+                                velocity: (2.2649, 2.2649),
+                                acceleration: (-123.5318, -123.5310),
+                                time: 0.0611,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.1915, 4.1915),
+                                velocity: (1.6473, 1.6473),
+                                acceleration: (-111.1818, -111.1806),
+                                time: 0.0667,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.1978, 4.1978),
+                                velocity: (1.1269, 1.1269),
+                                acceleration: (-93.6643, -93.6636),
+                                time: 0.0722,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.1992, 4.1992),
+                                velocity: (1.0232, 1.0232),
+                                acceleration: (-74.6390, -74.6392),
+                                time: 0.0736,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            }
+                        ]
         ));
 
         time += delta_time;
@@ -823,124 +2076,157 @@ mod tests {
             pos: (3.5, 4.2),
             time: time,
             pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(second_results.is_ok());
         assert!(compare_results(
             second_results.unwrap(),
             vec![
-                ModelerResult {
-                    pos: (3.1086, 4.1058),
-                    velocity: (5.2142, 4.6131),
-                    acceleration: (141.6557, 15.4223),
-                    time: 0.0381,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.1368, 4.1265),
-                    velocity: (5.9103, 4.3532),
-                    acceleration: (146.1873, -54.5680),
-                    time: 0.0429,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.1681, 4.1450),
-                    velocity: (6.5742, 3.8917),
-                    acceleration: (139.4012, -96.9169),
-                    time: 0.0476,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.2022, 4.1609),
-                    velocity: (7.1724, 3.3285),
-                    acceleration: (125.6306, -118.2742),
-                    time: 0.0524,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.2388, 4.1739),
-                    velocity: (7.6876, 2.7361),
-                    acceleration: (108.1908, -124.4087),
-                    time: 0.0571,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.2775, 4.1842),
-                    velocity: (8.1138, 2.1640),
-                    acceleration: (89.5049, -120.1309),
-                    time: 0.0619,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.3177, 4.1920),
-                    velocity: (8.4531, 1.6436),
-                    acceleration: (71.2473, -109.2959),
-                    time: 0.0667,
-                    pressure: 1.0
-                }
-            ]
+                            ModelerResult {
+                                pos: (3.1086, 4.1058),
+                                velocity: (5.2142, 4.6131),
+                                acceleration: (141.6557, 15.4223),
+                                time: 0.0381,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.1368, 4.1265),
+                                velocity: (5.9103, 4.3532),
+                                acceleration: (146.1873, -54.5680),
+                                time: 0.0429,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.1681, 4.1450),
+                                velocity: (6.5742, 3.8917),
+                                acceleration: (139.4012, -96.9169),
+                                time: 0.0476,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.2022, 4.1609),
+                                velocity: (7.1724, 3.3285),
+                                acceleration: (125.6306, -118.2742),
+                                time: 0.0524,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.2388, 4.1739),
+                                velocity: (7.6876, 2.7361),
+                                acceleration: (108.1908, -124.4087),
+                                time: 0.0571,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.2775, 4.1842),
+                                velocity: (8.1138, 2.1640),
+                                acceleration: (89.5049, -120.1309),
+                                time: 0.0619,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.3177, 4.1920),
+                                velocity: (8.4531, 1.6436),
+                                acceleration: (71.2473, -109.2959),
+                                time: 0.0667,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            }
+                        ]
         ));
 
         assert!(engine.predict().is_ok());
         assert!(compare_results(
             engine.predict().unwrap(),
             vec![
-                ModelerResult {
-                    pos: (3.3625, 4.1982),
-                    velocity: (8.0545, 1.1165),
-                    acceleration: (-71.7427, -94.8765),
-                    time: 0.0722,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.4018, 4.2021),
-                    velocity: (7.0831, 0.6987),
-                    acceleration: (-174.8469, -75.1957),
-                    time: 0.0778,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.4344, 4.2043),
-                    velocity: (5.8564, 0.3846),
-                    acceleration: (-220.8140, -56.5515),
-                    time: 0.0833,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.4598, 4.2052),
-                    velocity: (4.5880, 0.1611),
-                    acceleration: (-228.3204, -40.2244),
-                    time: 0.0889,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.4788, 4.2052),
-                    velocity: (3.4098, 0.0124),
-                    acceleration: (-212.0678, -26.7709),
-                    time: 0.0944,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.4921, 4.2048),
-                    velocity: (2.3929, -0.0780),
-                    acceleration: (-183.0373, -16.2648),
-                    time: 0.1000,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.4976, 4.2045),
-                    velocity: (1.9791, -0.1015),
-                    acceleration: (-148.9792, -8.4822),
-                    time: 0.1028,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.5001, 4.2044),
-                    velocity: (1.7911, -0.1098),
-                    acceleration: (-135.3759, -5.9543),
-                    time: 0.1042,
-                    pressure: 1.0
-                }
-            ]
+                            ModelerResult {
+                                pos: (3.3625, 4.1982),
+                                velocity: (8.0545, 1.1165),
+                                acceleration: (-71.7427, -94.8765),
+                                time: 0.0722,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.4018, 4.2021),
+                                velocity: (7.0831, 0.6987),
+                                acceleration: (-174.8469, -75.1957),
+                                time: 0.0778,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.4344, 4.2043),
+                                velocity: (5.8564, 0.3846),
+                                acceleration: (-220.8140, -56.5515),
+                                time: 0.0833,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.4598, 4.2052),
+                                velocity: (4.5880, 0.1611),
+                                acceleration: (-228.3204, -40.2244),
+                                time: 0.0889,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.4788, 4.2052),
+                                velocity: (3.4098, 0.0124),
+                                acceleration: (-212.0678, -26.7709),
+                                time: 0.0944,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.4921, 4.2048),
+                                velocity: (2.3929, -0.0780),
+                                acceleration: (-183.0373, -16.2648),
+                                time: 0.1000,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.4976, 4.2045),
+                                velocity: (1.9791, -0.1015),
+                                acceleration: (-148.9792, -8.4822),
+                                time: 0.1028,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.5001, 4.2044),
+                                velocity: (1.7911, -0.1098),
+                                acceleration: (-135.3759, -5.9543),
+                                time: 0.1042,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            }
+                        ]
         ));
 
         // we get more strokes as the model catches up to the anchor postion
@@ -950,110 +2236,141 @@ mod tests {
             pos: (3.7, 4.4),
             time: time,
             pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(update.is_ok());
         assert!(compare_results(
             update.unwrap(),
             vec![
-                ModelerResult {
-                    pos: (3.3583, 4.1996),
-                    velocity: (8.5122, 1.5925),
-                    acceleration: (12.4129, -10.7201),
-                    time: 0.0714,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.3982, 4.2084),
-                    velocity: (8.3832, 1.8534),
-                    acceleration: (-27.0783, 54.7731),
-                    time: 0.0762,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.4369, 4.2194),
-                    velocity: (8.1393, 2.3017),
-                    acceleration: (-51.2222, 94.1542),
-                    time: 0.0810,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.4743, 4.2329),
-                    velocity: (7.8362, 2.8434),
-                    acceleration: (-63.6668, 113.7452),
-                    time: 0.0857,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.5100, 4.2492),
-                    velocity: (7.5143, 3.4101),
-                    acceleration: (-67.5926, 119.0224),
-                    time: 0.0905,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.5443, 4.2680),
-                    velocity: (7.2016, 3.9556),
-                    acceleration: (-65.6568, 114.5394),
-                    time: 0.0952,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.5773, 4.2892),
-                    velocity: (6.9159, 4.4505),
-                    acceleration: (-59.9999, 103.9444),
-                    time: 0.1000,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.6115, 4.3141),
-                    velocity: (6.1580, 4.4832),
-                    acceleration: (-136.4312, 5.8833),
-                    time: 0.1056,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.6400, 4.3369),
-                    velocity: (5.1434, 4.0953),
-                    acceleration: (-182.6254, -69.8314),
-                    time: 0.1111,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.6626, 4.3563),
-                    velocity: (4.0671, 3.4902),
-                    acceleration: (-193.7401, -108.9119),
-                    time: 0.1167,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.6796, 4.3719),
-                    velocity: (3.0515, 2.8099),
-                    acceleration: (-182.7957, -122.4598),
-                    time: 0.1222,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.6916, 4.3838),
-                    velocity: (2.1648, 2.1462),
-                    acceleration: (-159.6116, -119.4551),
-                    time: 0.1278,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.6996, 4.3924),
-                    velocity: (1.4360, 1.5529),
-                    acceleration: (-131.1906, -106.7926),
-                    time: 0.1333,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (3.7028, 4.3960),
-                    velocity: (1.1520, 1.3044),
-                    acceleration: (-102.2117, -89.4872),
-                    time: 0.1361,
-                    pressure: 1.0
-                }
-            ]
+                            ModelerResult {
+                                pos: (3.3583, 4.1996),
+                                velocity: (8.5122, 1.5925),
+                                acceleration: (12.4129, -10.7201),
+                                time: 0.0714,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.3982, 4.2084),
+                                velocity: (8.3832, 1.8534),
+                                acceleration: (-27.0783, 54.7731),
+                                time: 0.0762,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.4369, 4.2194),
+                                velocity: (8.1393, 2.3017),
+                                acceleration: (-51.2222, 94.1542),
+                                time: 0.0810,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.4743, 4.2329),
+                                velocity: (7.8362, 2.8434),
+                                acceleration: (-63.6668, 113.7452),
+                                time: 0.0857,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.5100, 4.2492),
+                                velocity: (7.5143, 3.4101),
+                                acceleration: (-67.5926, 119.0224),
+                                time: 0.0905,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.5443, 4.2680),
+                                velocity: (7.2016, 3.9556),
+                                acceleration: (-65.6568, 114.5394),
+                                time: 0.0952,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.5773, 4.2892),
+                                velocity: (6.9159, 4.4505),
+                                acceleration: (-59.9999, 103.9444),
+                                time: 0.1000,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.6115, 4.3141),
+                                velocity: (6.1580, 4.4832),
+                                acceleration: (-136.4312, 5.8833),
+                                time: 0.1056,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.6400, 4.3369),
+                                velocity: (5.1434, 4.0953),
+                                acceleration: (-182.6254, -69.8314),
+                                time: 0.1111,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.6626, 4.3563),
+                                velocity: (4.0671, 3.4902),
+                                acceleration: (-193.7401, -108.9119),
+                                time: 0.1167,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.6796, 4.3719),
+                                velocity: (3.0515, 2.8099),
+                                acceleration: (-182.7957, -122.4598),
+                                time: 0.1222,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.6916, 4.3838),
+                                velocity: (2.1648, 2.1462),
+                                acceleration: (-159.6116, -119.4551),
+                                time: 0.1278,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.6996, 4.3924),
+                                velocity: (1.4360, 1.5529),
+                                acceleration: (-131.1906, -106.7926),
+                                time: 0.1333,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (3.7028, 4.3960),
+                                velocity: (1.1520, 1.3044),
+                                acceleration: (-102.2117, -89.4872),
+                                time: 0.1361,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            }
+                        ]
         ));
 
         // the stroke is finised, we get an error if we predict it
@@ -1067,6 +2384,9 @@ mod tests {
             pos: (3.0, 4.0),
             time: 0.0,
             pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
         };
         let mut engine = StrokeModeler::default();
         assert!(engine.update(input.clone()).is_ok());
@@ -1088,17 +2408,22 @@ mod tests {
             pos: (5.0, -3.0),
             time: time,
             pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
         });
 
         assert!(res1.is_ok());
         assert!(compare_results(
             res1.unwrap(),
             vec![ModelerResult {
-                pos: (5.0, -3.0),
-                time: time,
-                velocity: (0.0, 0.0),
-                acceleration: (0.0, 0.0),
-                pressure: 1.0
+                            pos: (5.0, -3.0),
+                            time: time,
+                            velocity: (0.0, 0.0),
+                            acceleration: (0.0, 0.0),
+                            pressure: 1.0,
+                            tilt: None,
+                            orientation: None,
             }]
         ));
 
@@ -1111,86 +2436,109 @@ mod tests {
             pos: (5.0, -3.1),
             time: time,
             pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(res2.is_ok());
         assert!(compare_results(
             res2.unwrap(),
             vec![ModelerResult {
-                pos: (5.0, -3.0033),
-                velocity: (0.0, -0.9818),
-                acceleration: (0.0, -294.5452),
-                time: 2.0033,
-                pressure: 1.0
+                            pos: (5.0, -3.0033),
+                            velocity: (0.0, -0.9818),
+                            acceleration: (0.0, -294.5452),
+                            time: 2.0033,
+                            pressure: 1.0,
+                            tilt: None,
+                            orientation: None,
             }]
         ));
 
         assert!(compare_results(
             engine.predict().unwrap(),
             vec![
-                ModelerResult {
-                    pos: (5.0, -3.0153),
-                    velocity: (0.0, -2.1719),
-                    acceleration: (0.0, -214.2145),
-                    time: 2.0089,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (5.0, -3.0303),
-                    velocity: (0.0, -2.6885),
-                    acceleration: (0.0, -92.9885),
-                    time: 2.0144,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (5.0, -3.0456),
-                    velocity: (0.0, -2.7541),
-                    acceleration: (0.0, -11.7992),
-                    time: 2.0200,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (5.0, -3.0597),
-                    velocity: (0.0, -2.5430),
-                    acceleration: (0.0, 37.9868),
-                    time: 2.0256,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (5.0, -3.0718),
-                    velocity: (0.0, -2.1852),
-                    acceleration: (0.0, 64.4053),
-                    time: 2.0311,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (5.0, -3.0817),
-                    velocity: (0.0, -1.7719),
-                    acceleration: (0.0, 74.4011),
-                    time: 2.0367,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (5.0, -3.0893),
-                    velocity: (0.0, -1.3628),
-                    acceleration: (0.0, 73.6345),
-                    time: 2.0422,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (5.0, -3.0948),
-                    velocity: (0.0, -0.9934),
-                    acceleration: (0.0, 66.4807),
-                    time: 2.0478,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (5.0, -3.0986),
-                    velocity: (0.0, -0.6815),
-                    acceleration: (0.0, 56.1448),
-                    time: 2.0533,
-                    pressure: 1.0
-                }
-            ]
+                            ModelerResult {
+                                pos: (5.0, -3.0153),
+                                velocity: (0.0, -2.1719),
+                                acceleration: (0.0, -214.2145),
+                                time: 2.0089,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (5.0, -3.0303),
+                                velocity: (0.0, -2.6885),
+                                acceleration: (0.0, -92.9885),
+                                time: 2.0144,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (5.0, -3.0456),
+                                velocity: (0.0, -2.7541),
+                                acceleration: (0.0, -11.7992),
+                                time: 2.0200,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (5.0, -3.0597),
+                                velocity: (0.0, -2.5430),
+                                acceleration: (0.0, 37.9868),
+                                time: 2.0256,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (5.0, -3.0718),
+                                velocity: (0.0, -2.1852),
+                                acceleration: (0.0, 64.4053),
+                                time: 2.0311,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (5.0, -3.0817),
+                                velocity: (0.0, -1.7719),
+                                acceleration: (0.0, 74.4011),
+                                time: 2.0367,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (5.0, -3.0893),
+                                velocity: (0.0, -1.3628),
+                                acceleration: (0.0, 73.6345),
+                                time: 2.0422,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (5.0, -3.0948),
+                                velocity: (0.0, -0.9934),
+                                acceleration: (0.0, 66.4807),
+                                time: 2.0478,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (5.0, -3.0986),
+                                velocity: (0.0, -0.6815),
+                                acceleration: (0.0, 56.1448),
+                                time: 2.0533,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            }
+                        ]
         ));
         assert!(engine.predict().is_ok());
 
@@ -1201,16 +2549,21 @@ mod tests {
             pos: (4.975, -3.175),
             time: time,
             pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(res3.is_ok());
         assert!(compare_results(
             res3.unwrap(),
             vec![ModelerResult {
-                pos: (4.9992, -3.0114),
-                velocity: (-0.2455, -2.4322),
-                acceleration: (-73.6366, -435.1238),
-                time: 2.0067,
-                pressure: 1.0
+                            pos: (4.9992, -3.0114),
+                            velocity: (-0.2455, -2.4322),
+                            acceleration: (-73.6366, -435.1238),
+                            time: 2.0067,
+                            pressure: 1.0,
+                            tilt: None,
+                            orientation: None,
             }]
         ));
 
@@ -1218,70 +2571,88 @@ mod tests {
         assert!(compare_results(
             engine.predict().unwrap(),
             vec![
-                ModelerResult {
-                    pos: (4.9962, -3.0344),
-                    velocity: (-0.5430, -4.1368),
-                    acceleration: (-53.5537, -306.8140),
-                    time: 2.0122,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.9924, -3.0609),
-                    velocity: (-0.6721, -4.7834),
-                    acceleration: (-23.2474, -116.3963),
-                    time: 2.0178,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.9886, -3.0873),
-                    velocity: (-0.6885, -4.7365),
-                    acceleration: (-2.9498, 8.4358),
-                    time: 2.0233,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.9851, -3.1110),
-                    velocity: (-0.6358, -4.2778),
-                    acceleration: (9.4971, 82.5682),
-                    time: 2.0289,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.9820, -3.1311),
-                    velocity: (-0.5463, -3.6137),
-                    acceleration: (16.1014, 119.5413),
-                    time: 2.0344,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.9796, -3.1471),
-                    velocity: (-0.4430, -2.8867),
-                    acceleration: (18.6005, 130.8578),
-                    time: 2.0400,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.9777, -3.1593),
-                    velocity: (-0.3407, -2.1881),
-                    acceleration: (18.4089, 125.7516),
-                    time: 2.0456,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.9763, -3.1680),
-                    velocity: (-0.2484, -1.5700),
-                    acceleration: (16.6198, 111.2560),
-                    time: 2.0511,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.9754, -3.1739),
-                    velocity: (-0.1704, -1.0564),
-                    acceleration: (14.0365, 92.4447),
-                    time: 2.0567,
-                    pressure: 1.0
-                }
-            ]
+                            ModelerResult {
+                                pos: (4.9962, -3.0344),
+                                velocity: (-0.5430, -4.1368),
+                                acceleration: (-53.5537, -306.8140),
+                                time: 2.0122,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.9924, -3.0609),
+                                velocity: (-0.6721, -4.7834),
+                                acceleration: (-23.2474, -116.3963),
+                                time: 2.0178,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.9886, -3.0873),
+                                velocity: (-0.6885, -4.7365),
+                                acceleration: (-2.9498, 8.4358),
+                                time: 2.0233,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.9851, -3.1110),
+                                velocity: (-0.6358, -4.2778),
+                                acceleration: (9.4971, 82.5682),
+                                time: 2.0289,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.9820, -3.1311),
+                                velocity: (-0.5463, -3.6137),
+                                acceleration: (16.1014, 119.5413),
+                                time: 2.0344,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.9796, -3.1471),
+                                velocity: (-0.4430, -2.8867),
+                                acceleration: (18.6005, 130.8578),
+                                time: 2.0400,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.9777, -3.1593),
+                                velocity: (-0.3407, -2.1881),
+                                acceleration: (18.4089, 125.7516),
+                                time: 2.0456,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.9763, -3.1680),
+                                velocity: (-0.2484, -1.5700),
+                                acceleration: (16.6198, 111.2560),
+                                time: 2.0511,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.9754, -3.1739),
+                                velocity: (-0.1704, -1.0564),
+                                acceleration: (14.0365, 92.4447),
+                                time: 2.0567,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            }
+                        ]
         ));
 
         time += delta_time;
@@ -1290,16 +2661,21 @@ mod tests {
             pos: (4.9, -3.2),
             time: time,
             pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(res4.is_ok());
         assert!(compare_results(
             res4.unwrap(),
             vec![ModelerResult {
-                pos: (4.9953, -3.0237),
-                velocity: (-1.1603, -3.7004),
-                acceleration: (-274.4622, -380.4507),
-                time: 2.0100,
-                pressure: 1.0
+                            pos: (4.9953, -3.0237),
+                            velocity: (-1.1603, -3.7004),
+                            acceleration: (-274.4622, -380.4507),
+                            time: 2.0100,
+                            pressure: 1.0,
+                            tilt: None,
+                            orientation: None,
             }]
         ));
 
@@ -1307,70 +2683,88 @@ mod tests {
         assert!(compare_results(
             engine.predict().unwrap(),
             vec![
-                ModelerResult {
-                    pos: (4.9828, -3.0521),
-                    velocity: (-2.2559, -5.1049),
-                    acceleration: (-197.1994, -252.8115),
-                    time: 2.0156,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.9677, -3.0825),
-                    velocity: (-2.7081, -5.4835),
-                    acceleration: (-81.4051, -68.1520),
-                    time: 2.0211,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.9526, -3.1115),
-                    velocity: (-2.7333, -5.2122),
-                    acceleration: (-4.5282, 48.8396),
-                    time: 2.0267,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.9387, -3.1369),
-                    velocity: (-2.4999, -4.5756),
-                    acceleration: (42.0094, 114.5943),
-                    time: 2.0322,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.9268, -3.1579),
-                    velocity: (-2.1326, -3.7776),
-                    acceleration: (66.1132, 143.6292),
-                    time: 2.0378,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.9173, -3.1743),
-                    velocity: (-1.7184, -2.9554),
-                    acceleration: (74.5656, 147.9932),
-                    time: 2.0433,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.9100, -3.1865),
-                    velocity: (-1.3136, -2.1935),
-                    acceleration: (72.8575, 137.1578),
-                    time: 2.0489,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.9047, -3.1950),
-                    velocity: (-0.9513, -1.5369),
-                    acceleration: (65.2090, 118.1874),
-                    time: 2.0544,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.9011, -3.2006),
-                    velocity: (-0.6475, -1.0032),
-                    acceleration: (54.6929, 96.0608),
-                    time: 2.0600,
-                    pressure: 1.0
-                }
-            ]
+                            ModelerResult {
+                                pos: (4.9828, -3.0521),
+                                velocity: (-2.2559, -5.1049),
+                                acceleration: (-197.1994, -252.8115),
+                                time: 2.0156,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.9677, -3.0825),
+                                velocity: (-2.7081, -5.4835),
+                                acceleration: (-81.4051, -68.1520),
+                                time: 2.0211,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.9526, -3.1115),
+                                velocity: (-2.7333, -5.2122),
+                                acceleration: (-4.5282, 48.8396),
+                                time: 2.0267,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.9387, -3.1369),
+                                velocity: (-2.4999, -4.5756),
+                                acceleration: (42.0094, 114.5943),
+                                time: 2.0322,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.9268, -3.1579),
+                                velocity: (-2.1326, -3.7776),
+                                acceleration: (66.1132, 143.6292),
+                                time: 2.0378,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.9173, -3.1743),
+                                velocity: (-1.7184, -2.9554),
+                                acceleration: (74.5656, 147.9932),
+                                time: 2.0433,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.9100, -3.1865),
+                                velocity: (-1.3136, -2.1935),
+                                acceleration: (72.8575, 137.1578),
+                                time: 2.0489,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.9047, -3.1950),
+                                velocity: (-0.9513, -1.5369),
+                                acceleration: (65.2090, 118.1874),
+                                time: 2.0544,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.9011, -3.2006),
+                                velocity: (-0.6475, -1.0032),
+                                acceleration: (54.6929, 96.0608),
+                                time: 2.0600,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            }
+                        ]
         ));
 
         time += delta_time;
@@ -1379,6 +2773,9 @@ mod tests {
             pos: (4.825, -3.2),
             time: time,
             pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
         });
 
         assert!(res5.is_ok());
@@ -1390,6 +2787,8 @@ mod tests {
                 acceleration: (-418.1093, -252.8115),
                 time: 2.0133,
                 pressure: 1.0,
+                tilt: None,
+                orientation: None,
             }]
         ));
 
@@ -1397,77 +2796,97 @@ mod tests {
         assert!(compare_results(
             engine.predict().unwrap(),
             vec![
-                ModelerResult {
-                    pos: (4.9636, -3.0687),
-                    velocity: (-4.1801, -5.3627),
-                    acceleration: (-292.6871, -147.5319),
-                    time: 2.0189,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.9370, -3.0985),
-                    velocity: (-4.7757, -5.3670),
-                    acceleration: (-107.2116, -0.7651),
-                    time: 2.0244,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.9109, -3.1256),
-                    velocity: (-4.6989, -4.8816),
-                    acceleration: (13.8210, 87.3644),
-                    time: 2.0300,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.8875, -3.1486),
-                    velocity: (-4.2257, -4.1466),
-                    acceleration: (85.1835, 132.2997),
-                    time: 2.0356,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.8677, -3.1671),
-                    velocity: (-3.5576, -3.3287),
-                    acceleration: (120.2579, 147.2335),
-                    time: 2.0411,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.8520, -3.1812),
-                    velocity: (-2.8333, -2.5353),
-                    acceleration: (130.3700, 142.8088),
-                    time: 2.0467,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.8401, -3.1914),
-                    velocity: (-2.1411, -1.8288),
-                    acceleration: (124.5846, 127.1714),
-                    time: 2.0522,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.8316, -3.1982),
-                    velocity: (-1.5312, -1.2386),
-                    acceleration: (109.7874, 106.2279),
-                    time: 2.0578,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.8280, -3.2010),
-                    velocity: (-1.2786, -1.0053),
-                    acceleration: (90.9288, 84.0051),
-                    time: 2.0606,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.8272, -3.2017),
-                    velocity: (-1.2209, -0.9529),
-                    acceleration: (83.2052, 75.4288),
-                    time: 2.0613,
-                    pressure: 1.0
-                }
-            ]
+                            ModelerResult {
+                                pos: (4.9636, -3.0687),
+                                velocity: (-4.1801, -5.3627),
+                                acceleration: (-292.6871, -147.5319),
+                                time: 2.0189,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.9370, -3.0985),
+                                velocity: (-4.7757, -5.3670),
+                                acceleration: (-107.2116, -0.7651),
+                                time: 2.0244,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.9109, -3.1256),
+                                velocity: (-4.6989, -4.8816),
+                                acceleration: (13.8210, 87.3644),
+                                time: 2.0300,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.8875, -3.1486),
+                                velocity: (-4.2257, -4.1466),
+                                acceleration: (85.1835, 132.2997),
+                                time: 2.0356,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.8677, -3.1671),
+                                velocity: (-3.5576, -3.3287),
+                                acceleration: (120.2579, 147.2335),
+                                time: 2.0411,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.8520, -3.1812),
+                                velocity: (-2.8333, -2.5353),
+                                acceleration: (130.3700, 142.8088),
+                                time: 2.0467,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.8401, -3.1914),
+                                velocity: (-2.1411, -1.8288),
+                                acceleration: (124.5846, 127.1714),
+                                time: 2.0522,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.8316, -3.1982),
+                                velocity: (-1.5312, -1.2386),
+                                acceleration: (109.7874, 106.2279),
+                                time: 2.0578,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.8280, -3.2010),
+                                velocity: (-1.2786, -1.0053),
+                                acceleration: (90.9288, 84.0051),
+                                time: 2.0606,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.8272, -3.2017),
+                                velocity: (-1.2209, -0.9529),
+                                acceleration: (83.2052, 75.4288),
+                                time: 2.0613,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            }
+                        ]
         ));
 
         time += delta_time;
@@ -1476,16 +2895,21 @@ mod tests {
             pos: (4.75, -3.225),
             time: time,
             pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(res6.is_ok());
         assert!(compare_results(
             res6.unwrap(),
             vec![ModelerResult {
-                pos: (4.9726, -3.0565),
-                velocity: (-4.2660, -5.2803),
-                acceleration: (-513.5957, -221.1678),
-                time: 2.0167,
-                pressure: 1.0
+                            pos: (4.9726, -3.0565),
+                            velocity: (-4.2660, -5.2803),
+                            acceleration: (-513.5957, -221.1678),
+                            time: 2.0167,
+                            pressure: 1.0,
+                            tilt: None,
+                            orientation: None,
             }]
         ));
 
@@ -1493,70 +2917,88 @@ mod tests {
         assert!(compare_results(
             engine.predict().unwrap(),
             vec![
-                ModelerResult {
-                    pos: (4.9381, -3.0894),
-                    velocity: (-6.2018, -5.9261),
-                    acceleration: (-348.4476, -116.2445),
-                    time: 2.0222,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.9004, -3.1215),
-                    velocity: (-6.7995, -5.7749),
-                    acceleration: (-107.5834, 27.2264),
-                    time: 2.0278,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.8640, -3.1501),
-                    velocity: (-6.5400, -5.1591),
-                    acceleration: (46.7146, 110.8336),
-                    time: 2.0333,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.8319, -3.1741),
-                    velocity: (-5.7897, -4.3207),
-                    acceleration: (135.0462, 150.9226),
-                    time: 2.0389,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.8051, -3.1932),
-                    velocity: (-4.8132, -3.4248),
-                    acceleration: (175.7684, 161.2555),
-                    time: 2.0444,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7841, -3.2075),
-                    velocity: (-3.7898, -2.5759),
-                    acceleration: (184.2227, 152.7958),
-                    time: 2.0500,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7683, -3.2176),
-                    velocity: (-2.8312, -1.8324),
-                    acceleration: (172.5480, 133.8294),
-                    time: 2.0556,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7572, -3.2244),
-                    velocity: (-1.9986, -1.2198),
-                    acceleration: (149.8577, 110.2830),
-                    time: 2.0611,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7526, -3.2271),
-                    velocity: (-1.6580, -0.9805),
-                    acceleration: (122.6198, 86.1299),
-                    time: 2.0639,
-                    pressure: 1.0
-                }
-            ]
+                            ModelerResult {
+                                pos: (4.9381, -3.0894),
+                                velocity: (-6.2018, -5.9261),
+                                acceleration: (-348.4476, -116.2445),
+                                time: 2.0222,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.9004, -3.1215),
+                                velocity: (-6.7995, -5.7749),
+                                acceleration: (-107.5834, 27.2264),
+                                time: 2.0278,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.8640, -3.1501),
+                                velocity: (-6.5400, -5.1591),
+                                acceleration: (46.7146, 110.8336),
+                                time: 2.0333,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.8319, -3.1741),
+                                velocity: (-5.7897, -4.3207),
+                                acceleration: (135.0462, 150.9226),
+                                time: 2.0389,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.8051, -3.1932),
+                                velocity: (-4.8132, -3.4248),
+                                acceleration: (175.7684, 161.2555),
+                                time: 2.0444,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7841, -3.2075),
+                                velocity: (-3.7898, -2.5759),
+                                acceleration: (184.2227, 152.7958),
+                                time: 2.0500,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7683, -3.2176),
+                                velocity: (-2.8312, -1.8324),
+                                acceleration: (172.5480, 133.8294),
+                                time: 2.0556,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7572, -3.2244),
+                                velocity: (-1.9986, -1.2198),
+                                acceleration: (149.8577, 110.2830),
+                                time: 2.0611,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7526, -3.2271),
+                                velocity: (-1.6580, -0.9805),
+                                acceleration: (122.6198, 86.1299),
+                                time: 2.0639,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            }
+                        ]
         ));
 
         time += delta_time;
@@ -1565,93 +3007,118 @@ mod tests {
             pos: (4.7, -3.3),
             time: time,
             pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(res7.is_ok());
         assert!(compare_results(
             res7.unwrap(),
             vec![ModelerResult {
-                pos: (4.9529, -3.0778),
-                velocity: (-5.9184, -6.4042),
-                acceleration: (-495.7209, -337.1538),
-                time: 2.0200,
-                pressure: 1.0
+                            pos: (4.9529, -3.0778),
+                            velocity: (-5.9184, -6.4042),
+                            acceleration: (-495.7209, -337.1538),
+                            time: 2.0200,
+                            pressure: 1.0,
+                            tilt: None,
+                            orientation: None,
             }]
         ));
         assert!(engine.predict().is_ok());
         assert!(compare_results(
             engine.predict().unwrap(),
             vec![
-                ModelerResult {
-                    pos: (4.9101, -3.1194),
-                    velocity: (-7.6886, -7.4784),
-                    acceleration: (-318.6394, -193.3594),
-                    time: 2.0256,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.8654, -3.1607),
-                    velocity: (-8.0518, -7.4431),
-                    acceleration: (-65.3698, 6.3579),
-                    time: 2.0311,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.8235, -3.1982),
-                    velocity: (-7.5377, -6.7452),
-                    acceleration: (92.5345, 125.6104),
-                    time: 2.0367,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7872, -3.2299),
-                    velocity: (-6.5440, -5.7133),
-                    acceleration: (178.8654, 185.7426),
-                    time: 2.0422,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7574, -3.2553),
-                    velocity: (-5.3529, -4.5748),
-                    acceleration: (214.4027, 204.9362),
-                    time: 2.0478,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7344, -3.2746),
-                    velocity: (-4.1516, -3.4758),
-                    acceleration: (216.2348, 197.8224),
-                    time: 2.0533,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7174, -3.2885),
-                    velocity: (-3.0534, -2.5004),
-                    acceleration: (197.6767, 175.5702),
-                    time: 2.0589,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7056, -3.2979),
-                    velocity: (-2.1169, -1.6879),
-                    acceleration: (168.5711, 146.2573),
-                    time: 2.0644,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7030, -3.3000),
-                    velocity: (-1.9283, -1.5276),
-                    acceleration: (135.7820, 115.3739),
-                    time: 2.0658,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7017, -3.3010),
-                    velocity: (-1.8380, -1.4512),
-                    acceleration: (130.0928, 110.0859),
-                    time: 2.0665,
-                    pressure: 1.0
-                },
-            ]
+                            ModelerResult {
+                                pos: (4.9101, -3.1194),
+                                velocity: (-7.6886, -7.4784),
+                                acceleration: (-318.6394, -193.3594),
+                                time: 2.0256,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.8654, -3.1607),
+                                velocity: (-8.0518, -7.4431),
+                                acceleration: (-65.3698, 6.3579),
+                                time: 2.0311,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.8235, -3.1982),
+                                velocity: (-7.5377, -6.7452),
+                                acceleration: (92.5345, 125.6104),
+                                time: 2.0367,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7872, -3.2299),
+                                velocity: (-6.5440, -5.7133),
+                                acceleration: (178.8654, 185.7426),
+                                time: 2.0422,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7574, -3.2553),
+                                velocity: (-5.3529, -4.5748),
+                                acceleration: (214.4027, 204.9362),
+                                time: 2.0478,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7344, -3.2746),
+                                velocity: (-4.1516, -3.4758),
+                                acceleration: (216.2348, 197.8224),
+                                time: 2.0533,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7174, -3.2885),
+                                velocity: (-3.0534, -2.5004),
+                                acceleration: (197.6767, 175.5702),
+                                time: 2.0589,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7056, -3.2979),
+                                velocity: (-2.1169, -1.6879),
+                                acceleration: (168.5711, 146.2573),
+                                time: 2.0644,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7030, -3.3000),
+                                velocity: (-1.9283, -1.5276),
+                                acceleration: (135.7820, 115.3739),
+                                time: 2.0658,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7017, -3.3010),
+                                velocity: (-1.8380, -1.4512),
+                                acceleration: (130.0928, 110.0859),
+                                time: 2.0665,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                        ]
         ));
 
         time += delta_time;
@@ -1660,6 +3127,9 @@ mod tests {
             pos: (4.675, -3.4),
             time: time,
             pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(res8.is_ok());
         assert!(compare_results(
@@ -1669,78 +3139,98 @@ mod tests {
                 velocity: (-7.2260, -8.0305),
                 acceleration: (-392.2747, -487.9053),
                 time: 2.0233,
-                pressure: 1.0
-            },]
+                pressure: 1.0,
+                tilt: None,
+                orientation: None,
+},]
         ));
 
         assert!(engine.predict().is_ok());
         assert!(compare_results(
             engine.predict().unwrap(),
             vec![
-                ModelerResult {
-                    pos: (4.8816, -3.1582),
-                    velocity: (-8.4881, -9.6525),
-                    acceleration: (-227.1831, -291.9628),
-                    time: 2.0289,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.8345, -3.2124),
-                    velocity: (-8.4738, -9.7482),
-                    acceleration: (2.5870, -17.2266),
-                    time: 2.0344,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7918, -3.2619),
-                    velocity: (-7.6948, -8.9195),
-                    acceleration: (140.2131, 149.1810),
-                    time: 2.0400,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7555, -3.3042),
-                    velocity: (-6.5279, -7.6113),
-                    acceleration: (210.0428, 235.4638),
-                    time: 2.0456,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7264, -3.3383),
-                    velocity: (-5.2343, -6.1345),
-                    acceleration: (232.8451, 265.8274),
-                    time: 2.0511,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7043, -3.3643),
-                    velocity: (-3.9823, -4.6907),
-                    acceleration: (225.3593, 259.8790),
-                    time: 2.0567,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.6884, -3.3832),
-                    velocity: (-2.8691, -3.3980),
-                    acceleration: (200.3802, 232.6849),
-                    time: 2.0622,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.6776, -3.3961),
-                    velocity: (-1.9403, -2.3135),
-                    acceleration: (167.1764, 195.2152),
-                    time: 2.0678,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.6752, -3.3990),
-                    velocity: (-1.7569, -2.0983),
-                    acceleration: (132.0560, 154.9868),
-                    time: 2.0692,
-                    pressure: 1.0
-                },
-            ]
+                            ModelerResult {
+                                pos: (4.8816, -3.1582),
+                                velocity: (-8.4881, -9.6525),
+                                acceleration: (-227.1831, -291.9628),
+                                time: 2.0289,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.8345, -3.2124),
+                                velocity: (-8.4738, -9.7482),
+                                acceleration: (2.5870, -17.2266),
+                                time: 2.0344,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7918, -3.2619),
+                                velocity: (-7.6948, -8.9195),
+                                acceleration: (140.2131, 149.1810),
+                                time: 2.0400,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7555, -3.3042),
+                                velocity: (-6.5279, -7.6113),
+                                acceleration: (210.0428, 235.4638),
+                                time: 2.0456,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7264, -3.3383),
+                                velocity: (-5.2343, -6.1345),
+                                acceleration: (232.8451, 265.8274),
+                                time: 2.0511,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7043, -3.3643),
+                                velocity: (-3.9823, -4.6907),
+                                acceleration: (225.3593, 259.8790),
+                                time: 2.0567,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.6884, -3.3832),
+                                velocity: (-2.8691, -3.3980),
+                                acceleration: (200.3802, 232.6849),
+                                time: 2.0622,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.6776, -3.3961),
+                                velocity: (-1.9403, -2.3135),
+                                acceleration: (167.1764, 195.2152),
+                                time: 2.0678,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.6752, -3.3990),
+                                velocity: (-1.7569, -2.0983),
+                                acceleration: (132.0560, 154.9868),
+                                time: 2.0692,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                        ]
         ));
         time += delta_time;
 
@@ -1749,6 +3239,9 @@ mod tests {
             pos: (4.675, -3.525),
             time: time,
             pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(res9.is_ok());
         assert!(compare_results(
@@ -1758,78 +3251,98 @@ mod tests {
                 velocity: (-7.9833, -10.2310),
                 acceleration: (-227.1831, -660.1446),
                 time: 2.0267,
-                pressure: 1.0
-            },]
+                pressure: 1.0,
+                tilt: None,
+                orientation: None,
+},]
         ));
 
         assert!(engine.predict().is_ok());
         assert!(compare_results(
             engine.predict().unwrap(),
             vec![
-                ModelerResult {
-                    pos: (4.8549, -3.2079),
-                    velocity: (-8.5070, -12.4602),
-                    acceleration: (-94.2781, -401.2599),
-                    time: 2.0322,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.8102, -3.2783),
-                    velocity: (-8.0479, -12.6650),
-                    acceleration: (82.6390, -36.8616),
-                    time: 2.0378,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7711, -3.3429),
-                    velocity: (-7.0408, -11.6365),
-                    acceleration: (181.2765, 185.1286),
-                    time: 2.0433,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7389, -3.3983),
-                    velocity: (-5.7965, -9.9616),
-                    acceleration: (223.9801, 301.4933),
-                    time: 2.0489,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7137, -3.4430),
-                    velocity: (-4.5230, -8.0510),
-                    acceleration: (229.2397, 343.9032),
-                    time: 2.0544,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.6951, -3.4773),
-                    velocity: (-3.3477, -6.1727),
-                    acceleration: (211.5554, 338.0856),
-                    time: 2.0600,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.6821, -3.5022),
-                    velocity: (-2.3381, -4.4846),
-                    acceleration: (181.7131, 303.8597),
-                    time: 2.0656,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.6737, -3.5192),
-                    velocity: (-1.5199, -3.0641),
-                    acceleration: (147.2879, 255.7003),
-                    time: 2.0711,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.6718, -3.5231),
-                    velocity: (-1.3626, -2.7813),
-                    acceleration: (113.2437, 203.5595),
-                    time: 2.0725,
-                    pressure: 1.0
-                },
-            ]
+                            ModelerResult {
+                                pos: (4.8549, -3.2079),
+                                velocity: (-8.5070, -12.4602),
+                                acceleration: (-94.2781, -401.2599),
+                                time: 2.0322,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.8102, -3.2783),
+                                velocity: (-8.0479, -12.6650),
+                                acceleration: (82.6390, -36.8616),
+                                time: 2.0378,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7711, -3.3429),
+                                velocity: (-7.0408, -11.6365),
+                                acceleration: (181.2765, 185.1286),
+                                time: 2.0433,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7389, -3.3983),
+                                velocity: (-5.7965, -9.9616),
+                                acceleration: (223.9801, 301.4933),
+                                time: 2.0489,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7137, -3.4430),
+                                velocity: (-4.5230, -8.0510),
+                                acceleration: (229.2397, 343.9032),
+                                time: 2.0544,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.6951, -3.4773),
+                                velocity: (-3.3477, -6.1727),
+                                acceleration: (211.5554, 338.0856),
+                                time: 2.0600,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.6821, -3.5022),
+                                velocity: (-2.3381, -4.4846),
+                                acceleration: (181.7131, 303.8597),
+                                time: 2.0656,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.6737, -3.5192),
+                                velocity: (-1.5199, -3.0641),
+                                acceleration: (147.2879, 255.7003),
+                                time: 2.0711,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.6718, -3.5231),
+                                velocity: (-1.3626, -2.7813),
+                                acceleration: (113.2437, 203.5595),
+                                time: 2.0725,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                        ]
         ));
 
         time += delta_time;
@@ -1839,82 +3352,105 @@ mod tests {
             pos: (4.7, -3.6),
             time: time,
             pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(res10.is_ok());
         assert!(compare_results(
             res10.unwrap(),
             vec![
-                ModelerResult {
-                    pos: (4.8753, -3.1797),
-                    velocity: (-8.0521, -12.3049),
-                    acceleration: (-20.6429, -622.1685),
-                    time: 2.0300,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.8325, -3.2589),
-                    velocity: (-7.7000, -14.2607),
-                    acceleration: (63.3680, -352.0363),
-                    time: 2.0356,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7948, -3.3375),
-                    velocity: (-6.7888, -14.1377),
-                    acceleration: (164.0215, 22.1350),
-                    time: 2.0411,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7636, -3.4085),
-                    velocity: (-5.6249, -12.7787),
-                    acceleration: (209.5020, 244.6249),
-                    time: 2.0467,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7390, -3.4685),
-                    velocity: (-4.4152, -10.8015),
-                    acceleration: (217.7452, 355.8801),
-                    time: 2.0522,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7208, -3.5164),
-                    velocity: (-3.2880, -8.6333),
-                    acceleration: (202.8961, 390.2804),
-                    time: 2.0578,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.7079, -3.5528),
-                    velocity: (-2.3128, -6.5475),
-                    acceleration: (175.5414, 375.4407),
-                    time: 2.0633,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.6995, -3.5789),
-                    velocity: (-1.5174, -4.7008),
-                    acceleration: (143.1705, 332.4062),
-                    time: 2.0689,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.6945, -3.5965),
-                    velocity: (-0.9022, -3.1655),
-                    acceleration: (110.7325, 276.3669),
-                    time: 2.0744,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (4.6942, -3.5976),
-                    velocity: (-0.8740, -3.0899),
-                    acceleration: (81.2036, 217.6189),
-                    time: 2.0748,
-                    pressure: 1.0
-                },
-            ]
+                            ModelerResult {
+                                pos: (4.8753, -3.1797),
+                                velocity: (-8.0521, -12.3049),
+                                acceleration: (-20.6429, -622.1685),
+                                time: 2.0300,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.8325, -3.2589),
+                                velocity: (-7.7000, -14.2607),
+                                acceleration: (63.3680, -352.0363),
+                                time: 2.0356,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7948, -3.3375),
+                                velocity: (-6.7888, -14.1377),
+                                acceleration: (164.0215, 22.1350),
+                                time: 2.0411,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7636, -3.4085),
+                                velocity: (-5.6249, -12.7787),
+                                acceleration: (209.5020, 244.6249),
+                                time: 2.0467,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7390, -3.4685),
+                                velocity: (-4.4152, -10.8015),
+                                acceleration: (217.7452, 355.8801),
+                                time: 2.0522,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7208, -3.5164),
+                                velocity: (-3.2880, -8.6333),
+                                acceleration: (202.8961, 390.2804),
+                                time: 2.0578,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.7079, -3.5528),
+                                velocity: (-2.3128, -6.5475),
+                                acceleration: (175.5414, 375.4407),
+                                time: 2.0633,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.6995, -3.5789),
+                                velocity: (-1.5174, -4.7008),
+                                acceleration: (143.1705, 332.4062),
+                                time: 2.0689,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.6945, -3.5965),
+                                velocity: (-0.9022, -3.1655),
+                                acceleration: (110.7325, 276.3669),
+                                time: 2.0744,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (4.6942, -3.5976),
+                                velocity: (-0.8740, -3.0899),
+                                acceleration: (81.2036, 217.6189),
+                                time: 2.0748,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                        ]
         ));
 
         // the stroke is finished so there's nothing left to predict
@@ -1932,6 +3468,9 @@ mod tests {
             pos: (-6.0, -2.0),
             time: time,
             pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(res1.is_ok());
         assert!(compare_results(
@@ -1955,35 +3494,43 @@ mod tests {
         assert!(compare_results(
             res2.unwrap(),
             vec![
-                ModelerResult {
-                    pos: (-6.0003, -2.0),
-                    velocity: (-0.0615, 0.0),
-                    acceleration: (-14.7276, 0.0),
-                    time: 4.0042,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (-6.0009, -2.0),
-                    velocity: (-0.1628, 0.0),
-                    acceleration: (-24.2725, 0.0),
-                    time: 4.0084,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (-6.0021, -2.0),
-                    velocity: (-0.2868, 0.0),
-                    acceleration: (-29.6996, 0.0),
-                    time: 4.0125,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (-6.0039, -2.0),
-                    velocity: (-0.4203, 0.0),
-                    acceleration: (-31.9728, 0.0),
-                    time: 4.0167,
-                    pressure: 1.0
-                },
-            ]
+                            ModelerResult {
+                                pos: (-6.0003, -2.0),
+                                velocity: (-0.0615, 0.0),
+                                acceleration: (-14.7276, 0.0),
+                                time: 4.0042,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (-6.0009, -2.0),
+                                velocity: (-0.1628, 0.0),
+                                acceleration: (-24.2725, 0.0),
+                                time: 4.0084,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (-6.0021, -2.0),
+                                velocity: (-0.2868, 0.0),
+                                acceleration: (-29.6996, 0.0),
+                                time: 4.0125,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (-6.0039, -2.0),
+                                velocity: (-0.4203, 0.0),
+                                acceleration: (-31.9728, 0.0),
+                                time: 4.0167,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                        ]
         ));
 
         time += delta_time;
@@ -1992,205 +3539,760 @@ mod tests {
             pos: (-6.02, -2.02),
             time: time,
             pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(res3.is_ok());
         assert!(compare_results(
             res3.unwrap(),
             vec![
-                ModelerResult {
-                    pos: (-6.0059, -2.0001),
-                    velocity: (-0.4921, -0.0307),
-                    acceleration: (-17.1932, -7.3638),
-                    time: 4.0209,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (-6.0081, -2.0005),
-                    velocity: (-0.5170, -0.0814),
-                    acceleration: (-5.9729, -12.1355),
-                    time: 4.0251,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (-6.0102, -2.0010),
-                    velocity: (-0.5079, -0.1434),
-                    acceleration: (2.1807, -14.8493),
-                    time: 4.0292,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (-6.0122, -2.0019),
-                    velocity: (-0.4755, -0.2101),
-                    acceleration: (7.7710, -15.9860),
-                    time: 4.0334,
-                    pressure: 1.0
-                },
-            ]
-        ));
+                            ModelerResult {
+                                pos: (-6.0059, -2.0001),
+                                velocity: (-0.4921, -0.0307),
+                                acceleration: (-17.1932, -7.3638),
+                                time: 4.0209,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (-6.0081, -2.0005),
+                                velocity: (-0.5170, -0.0814),
+                                acceleration: (-5.9729, -12.1355),
+                                time: 4.0251,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (-6.0102, -2.0010),
+                                velocity: (-0.5079, -0.1434),
+                                acceleration: (2.1807, -14.8493),
+                                time: 4.0292,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (-6.0122, -2.0019),
+                                velocity: (-0.4755, -0.2101),
+                                acceleration: (7.7710, -15.9860),
+                                time: 4.0334,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                        ]
+        ));
         time += delta_time;
         let res4 = engine.update(ModelerInput {
             event_type: ModelerInputEventType::Move,
             pos: (-6.04, -2.02),
             time: time,
             pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(res4.is_ok());
         assert!(compare_results(
             res4.unwrap(),
             vec![
-                ModelerResult {
-                    pos: (-6.0141, -2.0030),
-                    velocity: (-0.4489, -0.2563),
-                    acceleration: (6.3733, -11.0507),
-                    time: 4.0376,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (-6.0159, -2.0042),
-                    velocity: (-0.4277, -0.2856),
-                    acceleration: (5.0670, -7.0315),
-                    time: 4.0418,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (-6.0176, -2.0055),
-                    velocity: (-0.4115, -0.3018),
-                    acceleration: (3.8950, -3.8603),
-                    time: 4.0459,
-                    pressure: 1.0
-                },
-                ModelerResult {
-                    pos: (-6.0193, -2.0067),
-                    velocity: (-0.3994, -0.3078),
-                    acceleration: (2.8758, -1.4435),
-                    time: 4.0501,
-                    pressure: 1.0
-                },
-            ]
+                            ModelerResult {
+                                pos: (-6.0141, -2.0030),
+                                velocity: (-0.4489, -0.2563),
+                                acceleration: (6.3733, -11.0507),
+                                time: 4.0376,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (-6.0159, -2.0042),
+                                velocity: (-0.4277, -0.2856),
+                                acceleration: (5.0670, -7.0315),
+                                time: 4.0418,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (-6.0176, -2.0055),
+                                velocity: (-0.4115, -0.3018),
+                                acceleration: (3.8950, -3.8603),
+                                time: 4.0459,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (-6.0193, -2.0067),
+                                velocity: (-0.3994, -0.3078),
+                                acceleration: (2.8758, -1.4435),
+                                time: 4.0501,
+                                pressure: 1.0,
+                                tilt: None,
+                                orientation: None,
+            },
+                        ]
+        ));
+
+        time += delta_time;
+        let res5 = engine.update(ModelerInput {
+            event_type: ModelerInputEventType::Move,
+            pos: (-6.04, -2.04),
+            time: time,
+            pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
+        });
+        assert!(res5.is_ok());
+        assert!(
+            (compare_results(
+                res5.unwrap(),
+                vec![
+                                    ModelerResult {
+                                        pos: (-6.0209, -2.0082),
+                                        velocity: (-0.3910, -0.3372),
+                                        acceleration: (2.0142, -7.0427),
+                                        time: 4.0543,
+                                        pressure: 1.0,
+                                        tilt: None,
+                                        orientation: None,
+                },
+                                    ModelerResult {
+                                        pos: (-6.0225, -2.0098),
+                                        velocity: (-0.3856, -0.3814),
+                                        acceleration: (1.3090, -10.5977),
+                                        time: 4.0585,
+                                        pressure: 1.0,
+                                        tilt: None,
+                                        orientation: None,
+                },
+                                    ModelerResult {
+                                        pos: (-6.0241, -2.0116),
+                                        velocity: (-0.3825, -0.4338),
+                                        acceleration: (0.7470, -12.5399),
+                                        time: 4.0626,
+                                        pressure: 1.0,
+                                        tilt: None,
+                                        orientation: None,
+                },
+                                    ModelerResult {
+                                        pos: (-6.0257, -2.0136),
+                                        velocity: (-0.3811, -0.4891),
+                                        acceleration: (0.3174, -13.2543),
+                                        time: 4.0668,
+                                        pressure: 1.0,
+                                        tilt: None,
+                                        orientation: None,
+                },
+                                ]
+            ))
+        );
+    }
+
+    #[test]
+    fn reset_stroke() {
+        let mut engine = StrokeModeler::default();
+        let delta_time = 1. / 50.;
+        let mut time = 0.0;
+        let res = engine.update(ModelerInput {
+            event_type: ModelerInputEventType::Down,
+            pos: (-8.0, -10.0),
+            time: time,
+            pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
+        });
+        assert!(res.is_ok());
+        assert!(!res.unwrap().is_empty());
+        assert!(engine.predict().is_ok());
+        assert!(engine.predict().unwrap().is_empty());
+
+        time += delta_time;
+        let res2 = engine.update(ModelerInput {
+            event_type: ModelerInputEventType::Move,
+            time: time,
+            ..ModelerInput::default()
+        });
+        assert!(res2.is_ok());
+        assert!(!res2.unwrap().is_empty());
+        assert!(engine.predict().is_ok());
+        assert!(!engine.predict().unwrap().is_empty());
+
+        time += delta_time;
+        let res3 = engine.update(ModelerInput {
+            event_type: ModelerInputEventType::Move,
+            time: time,
+            pos: (-11.0, -5.0),
+            ..ModelerInput::default()
+        });
+        assert!(res3.is_ok());
+        assert!(!res3.unwrap().is_empty());
+        assert!(engine.predict().is_ok());
+        assert!(!engine.predict().unwrap().is_empty());
+
+        engine.reset();
+        assert!(engine.predict().is_err());
+    }
+
+    #[test]
+    fn save_state_restore_state_round_trips_to_an_identical_continuation() {
+        let mut baseline = StrokeModeler::default();
+        let mut checkpointed = StrokeModeler::default();
+        let delta_time = 1. / 50.;
+        let mut time = 0.0;
+
+        for (modeler, pos) in [
+            (&mut baseline, (-8.0, -10.0)),
+            (&mut checkpointed, (-8.0, -10.0)),
+        ] {
+            modeler
+                .update(ModelerInput {
+                    event_type: ModelerInputEventType::Down,
+                    pos,
+                    time,
+                    ..ModelerInput::default()
+                })
+                .unwrap();
+        }
+        time += delta_time;
+        for modeler in [&mut baseline, &mut checkpointed] {
+            modeler
+                .update(ModelerInput {
+                    event_type: ModelerInputEventType::Move,
+                    pos: (-9.0, -7.0),
+                    time,
+                    ..ModelerInput::default()
+                })
+                .unwrap();
+        }
+
+        // snapshot `checkpointed` here, then keep feeding both modelers the same subsequent
+        // inputs: the one restored from the snapshot should produce bit-identical results to
+        // the one that was never interrupted
+        let snapshot = checkpointed.save_state();
+        checkpointed.restore_state(snapshot);
+
+        for next_time in [time + delta_time, time + 2.0 * delta_time] {
+            let input = ModelerInput {
+                event_type: ModelerInputEventType::Move,
+                pos: (-11.0, -5.0),
+                time: next_time,
+                ..ModelerInput::default()
+            };
+            let baseline_result = baseline.update(input.clone()).unwrap();
+            let checkpointed_result = checkpointed.update(input).unwrap();
+            assert_eq!(baseline_result, checkpointed_result);
+        }
+    }
+
+    #[test]
+    fn restore_with_no_prior_save_is_a_no_op() {
+        let mut engine = StrokeModeler::default();
+        engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Down,
+                pos: (1.0, 2.0),
+                time: 0.0,
+                ..ModelerInput::default()
+            })
+            .unwrap();
+        let before = engine.save_state();
+
+        engine.restore();
+
+        assert_eq!(before, engine.save_state());
+    }
+
+    #[test]
+    fn save_and_restore_let_speculative_moves_be_rolled_back_and_reprobed() {
+        let mut engine = StrokeModeler::default();
+        let delta_time = 1. / 50.;
+        let mut time = 0.0;
+
+        engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Down,
+                pos: (-8.0, -10.0),
+                time,
+                ..ModelerInput::default()
+            })
+            .unwrap();
+        time += delta_time;
+        engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Move,
+                pos: (-9.0, -7.0),
+                time,
+                ..ModelerInput::default()
+            })
+            .unwrap();
+
+        // checkpoint the committed stroke, then probe two different speculative
+        // continuations from it; both probes should see the same pre-probe state
+        engine.save();
+        let committed = engine.save_state();
+
+        let speculative_input = ModelerInput {
+            event_type: ModelerInputEventType::Move,
+            pos: (20.0, 30.0),
+            time: time + delta_time,
+            ..ModelerInput::default()
+        };
+        let first_probe = engine.update(speculative_input.clone()).unwrap();
+        engine.restore();
+        assert_eq!(committed, engine.save_state());
+
+        let second_probe = engine.update(speculative_input).unwrap();
+        assert_eq!(first_probe, second_probe);
+
+        engine.restore();
+        assert_eq!(committed, engine.save_state());
+    }
+
+    #[test]
+    fn ignore_input_before_down() {
+        let mut engine = StrokeModeler::default();
+
+        assert!(matches!(
+            engine
+                .update(ModelerInput {
+                    event_type: ModelerInputEventType::Move,
+                    ..ModelerInput::default()
+                })
+                .unwrap_err(),
+            ModelerError::Element {
+                src: ElementError::Order {
+                    src: ElementOrderError::UnexpectedMove { .. }
+                }
+            }
         ));
+        assert!(matches!(
+            engine
+                .update(ModelerInput {
+                    event_type: ModelerInputEventType::Up,
+                    ..ModelerInput::default()
+                })
+                .unwrap_err(),
+            ModelerError::Element {
+                src: ElementError::Order {
+                    src: ElementOrderError::UnexpectedUp { .. }
+                }
+            }
+        ));
+    }
+
+    #[test]
+    fn non_monotonic_time_is_rejected() {
+        let mut engine = StrokeModeler::default();
+        assert!(engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Down,
+                time: 1.0,
+                ..ModelerInput::default()
+            })
+            .is_ok());
+
+        assert!(matches!(
+            engine
+                .update(ModelerInput {
+                    event_type: ModelerInputEventType::Move,
+                    time: 0.5,
+                    ..ModelerInput::default()
+                })
+                .unwrap_err(),
+            ModelerError::Element {
+                src: ElementError::NegativeTimeDelta { .. }
+            }
+        ));
+    }
+
+    #[test]
+    fn duplicate_move_input_is_rejected() {
+        let mut engine = StrokeModeler::default();
+        assert!(engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Down,
+                time: 1.0,
+                ..ModelerInput::default()
+            })
+            .is_ok());
+
+        assert!(matches!(
+            engine
+                .update(ModelerInput {
+                    event_type: ModelerInputEventType::Move,
+                    time: 1.0,
+                    ..ModelerInput::default()
+                })
+                .unwrap_err(),
+            ModelerError::Element {
+                src: ElementError::Duplicate { .. }
+            }
+        ));
+    }
+
+    #[test]
+    fn time_gap_larger_than_sampling_max_time_gap_is_rejected_on_move() {
+        let mut engine = StrokeModeler::new(ModelerParams {
+            sampling_max_time_gap: 1.0,
+            ..ModelerParams::suggested()
+        })
+        .unwrap();
+        assert!(engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Down,
+                time: 0.0,
+                ..ModelerInput::default()
+            })
+            .is_ok());
+
+        assert!(matches!(
+            engine
+                .update(ModelerInput {
+                    event_type: ModelerInputEventType::Move,
+                    time: 2.0,
+                    ..ModelerInput::default()
+                })
+                .unwrap_err(),
+            ModelerError::Element {
+                src: ElementError::TooFarApart { time, previous_time, max_gap, .. }
+            } if time == 2.0 && previous_time == 0.0 && max_gap == 1.0
+        ));
+    }
+
+    #[test]
+    fn time_gap_larger_than_sampling_max_time_gap_is_rejected_on_up() {
+        let mut engine = StrokeModeler::new(ModelerParams {
+            sampling_max_time_gap: 1.0,
+            ..ModelerParams::suggested()
+        })
+        .unwrap();
+        assert!(engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Down,
+                time: 0.0,
+                ..ModelerInput::default()
+            })
+            .is_ok());
+
+        assert!(matches!(
+            engine
+                .update(ModelerInput {
+                    event_type: ModelerInputEventType::Up,
+                    time: 2.0,
+                    ..ModelerInput::default()
+                })
+                .unwrap_err(),
+            ModelerError::Element {
+                src: ElementError::TooFarApart { time, previous_time, max_gap, .. }
+            } if time == 2.0 && previous_time == 0.0 && max_gap == 1.0
+        ));
+    }
+
+    #[test]
+    fn move_output_is_capped_at_sampling_max_outputs_per_call() {
+        // an enormous `sampling_min_output_rate` would otherwise request far more than
+        // `sampling_max_outputs_per_call` interpolated points for a single, allowed gap
+        let mut engine = StrokeModeler::new(ModelerParams {
+            sampling_min_output_rate: 1_000_000.0,
+            sampling_max_time_gap: 10.0,
+            sampling_max_outputs_per_call: 5,
+            ..ModelerParams::suggested()
+        })
+        .unwrap();
+        engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Down,
+                time: 0.0,
+                ..ModelerInput::default()
+            })
+            .unwrap();
+
+        let out = engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Move,
+                time: 1.0,
+                ..ModelerInput::default()
+            })
+            .unwrap();
+
+        assert!(out.len() <= 5);
+    }
+
+    #[test]
+    fn rejected_input_leaves_the_modeler_state_unchanged() {
+        let mut engine = StrokeModeler::default();
+        engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Down,
+                pos: (0.0, 0.0),
+                time: 1.0,
+                pressure: 1.0,
+                ..ModelerInput::default()
+            })
+            .unwrap();
+        engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Move,
+                pos: (1.0, 0.0),
+                time: 1.016,
+                pressure: 1.0,
+                ..ModelerInput::default()
+            })
+            .unwrap();
+
+        // a duplicate timestamp is rejected...
+        assert!(matches!(
+            engine
+                .update(ModelerInput {
+                    event_type: ModelerInputEventType::Move,
+                    pos: (2.0, 0.0),
+                    time: 1.016,
+                    pressure: 1.0,
+                    ..ModelerInput::default()
+                })
+                .unwrap_err(),
+            ModelerError::Element {
+                src: ElementError::Duplicate { .. }
+            }
+        ));
+
+        // ...and the stroke continues exactly as if the rejected sample had never been offered
+        assert!(engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Move,
+                pos: (2.0, 0.0),
+                time: 1.032,
+                pressure: 1.0,
+                ..ModelerInput::default()
+            })
+            .is_ok());
+        assert!(engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Up,
+                pos: (2.0, 0.0),
+                time: 1.048,
+                pressure: 1.0,
+                ..ModelerInput::default()
+            })
+            .is_ok());
+    }
 
-        time += delta_time;
-        let res5 = engine.update(ModelerInput {
-            event_type: ModelerInputEventType::Move,
-            pos: (-6.04, -2.04),
-            time: time,
-            pressure: 1.0,
-        });
-        assert!(res5.is_ok());
-        assert!(
-            (compare_results(
-                res5.unwrap(),
-                vec![
-                    ModelerResult {
-                        pos: (-6.0209, -2.0082),
-                        velocity: (-0.3910, -0.3372),
-                        acceleration: (2.0142, -7.0427),
-                        time: 4.0543,
-                        pressure: 1.0
-                    },
-                    ModelerResult {
-                        pos: (-6.0225, -2.0098),
-                        velocity: (-0.3856, -0.3814),
-                        acceleration: (1.3090, -10.5977),
-                        time: 4.0585,
-                        pressure: 1.0
-                    },
-                    ModelerResult {
-                        pos: (-6.0241, -2.0116),
-                        velocity: (-0.3825, -0.4338),
-                        acceleration: (0.7470, -12.5399),
-                        time: 4.0626,
-                        pressure: 1.0
-                    },
-                    ModelerResult {
-                        pos: (-6.0257, -2.0136),
-                        velocity: (-0.3811, -0.4891),
-                        acceleration: (0.3174, -13.2543),
-                        time: 4.0668,
-                        pressure: 1.0
-                    },
-                ]
-            ))
-        );
+    #[test]
+    fn negative_pressure_is_rejected() {
+        let mut engine = StrokeModeler::default();
+        assert!(matches!(
+            engine
+                .update(ModelerInput {
+                    event_type: ModelerInputEventType::Down,
+                    pressure: -0.1,
+                    ..ModelerInput::default()
+                })
+                .unwrap_err(),
+            ModelerError::NegativePressure
+        ));
     }
 
     #[test]
-    fn reset_stroke() {
+    fn non_finite_input_is_rejected() {
         let mut engine = StrokeModeler::default();
-        let delta_time = 1. / 50.;
-        let mut time = 0.0;
-        let res = engine.update(ModelerInput {
-            event_type: ModelerInputEventType::Down,
-            pos: (-8.0, -10.0),
-            time: time,
-            pressure: 1.0,
-        });
-        assert!(res.is_ok());
-        assert!(!res.unwrap().is_empty());
-        assert!(engine.predict().is_ok());
-        assert!(engine.predict().unwrap().is_empty());
+        assert!(matches!(
+            engine
+                .update(ModelerInput {
+                    event_type: ModelerInputEventType::Down,
+                    pos: (f64::NAN, 0.0),
+                    ..ModelerInput::default()
+                })
+                .unwrap_err(),
+            ModelerError::NonFiniteInput
+        ));
+        assert!(matches!(
+            engine
+                .update(ModelerInput {
+                    event_type: ModelerInputEventType::Down,
+                    time: f64::INFINITY,
+                    ..ModelerInput::default()
+                })
+                .unwrap_err(),
+            ModelerError::NonFiniteInput
+        ));
+        assert!(matches!(
+            engine
+                .update(ModelerInput {
+                    event_type: ModelerInputEventType::Down,
+                    tilt: Some(f64::NAN),
+                    ..ModelerInput::default()
+                })
+                .unwrap_err(),
+            ModelerError::NonFiniteInput
+        ));
+    }
 
-        time += delta_time;
-        let res2 = engine.update(ModelerInput {
-            event_type: ModelerInputEventType::Move,
-            time: time,
-            ..ModelerInput::default()
-        });
-        assert!(res2.is_ok());
-        assert!(!res2.unwrap().is_empty());
-        assert!(engine.predict().is_ok());
-        assert!(!engine.predict().unwrap().is_empty());
+    #[test]
+    fn out_of_range_pressure_tilt_and_orientation_are_rejected() {
+        let mut engine = StrokeModeler::default();
+        assert!(matches!(
+            engine
+                .update(ModelerInput {
+                    event_type: ModelerInputEventType::Down,
+                    pressure: 1.5,
+                    ..ModelerInput::default()
+                })
+                .unwrap_err(),
+            ModelerError::OutOfRange {
+                field: "pressure",
+                ..
+            }
+        ));
+        assert!(matches!(
+            engine
+                .update(ModelerInput {
+                    event_type: ModelerInputEventType::Down,
+                    tilt: Some(std::f64::consts::PI),
+                    ..ModelerInput::default()
+                })
+                .unwrap_err(),
+            ModelerError::OutOfRange { field: "tilt", .. }
+        ));
+        assert!(matches!(
+            engine
+                .update(ModelerInput {
+                    event_type: ModelerInputEventType::Down,
+                    orientation: Some(-1.0),
+                    ..ModelerInput::default()
+                })
+                .unwrap_err(),
+            ModelerError::OutOfRange {
+                field: "orientation",
+                ..
+            }
+        ));
+    }
 
-        time += delta_time;
-        let res3 = engine.update(ModelerInput {
-            event_type: ModelerInputEventType::Move,
-            time: time,
-            pos: (-11.0, -5.0),
-            ..ModelerInput::default()
-        });
-        assert!(res3.is_ok());
-        assert!(!res3.unwrap().is_empty());
-        assert!(engine.predict().is_ok());
-        assert!(!engine.predict().unwrap().is_empty());
+    #[test]
+    fn disabling_strict_input_validation_lets_non_finite_input_through() {
+        let mut engine = StrokeModeler::new(ModelerParams {
+            strict_input_validation: false,
+            ..ModelerParams::suggested()
+        })
+        .unwrap();
 
-        engine.reset();
-        assert!(engine.predict().is_err());
+        // would be rejected with ModelerError::NonFiniteInput under the default strict mode
+        assert!(engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Down,
+                pos: (f64::NAN, 0.0),
+                ..ModelerInput::default()
+            })
+            .is_ok());
     }
 
     #[test]
-    fn ignore_input_before_down() {
+    fn tdown_in_progress_error() {
         let mut engine = StrokeModeler::default();
 
         assert!(engine
             .update(ModelerInput {
-                event_type: ModelerInputEventType::Move,
+                event_type: ModelerInputEventType::Down,
                 ..ModelerInput::default()
             })
-            .is_err());
+            .is_ok());
         assert!(engine
             .update(ModelerInput {
-                event_type: ModelerInputEventType::Up,
+                event_type: ModelerInputEventType::Down,
                 ..ModelerInput::default()
             })
             .is_err());
     }
 
     #[test]
-    fn tdown_in_progress_error() {
+    fn is_input_glitch_separates_recoverable_glitches_from_api_misuse() {
         let mut engine = StrokeModeler::default();
 
-        assert!(engine
+        // a malformed sample: recoverable, the caller can just drop it and keep streaming
+        let non_finite = engine
             .update(ModelerInput {
                 event_type: ModelerInputEventType::Down,
+                pos: (f64::NAN, 0.0),
                 ..ModelerInput::default()
             })
-            .is_ok());
-        assert!(engine
+            .unwrap_err();
+        assert!(non_finite.is_input_glitch());
+
+        // a `Move` with no `Down` yet: misuse of the API, not a glitch in one sample
+        let missing_down = engine
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Move,
+                ..ModelerInput::default()
+            })
+            .unwrap_err();
+        assert!(!missing_down.is_input_glitch());
+    }
+
+    #[test]
+    fn a_new_stroke_does_not_inherit_the_previous_stroke_wobble_smoothing_state() {
+        // a fresh engine's first stroke, used as the baseline
+        let mut fresh = StrokeModeler::default();
+
+        // an engine that already ran a very different, wobbly first stroke before starting
+        // the one under test; its wobble smoother window/EWMA state must not leak into the
+        // second stroke's moves
+        let mut reused = StrokeModeler::default();
+        reused
             .update(ModelerInput {
                 event_type: ModelerInputEventType::Down,
+                pos: (500.0, -500.0),
+                time: 0.0,
                 ..ModelerInput::default()
             })
-            .is_err());
+            .unwrap();
+        reused
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Move,
+                pos: (500.1, -499.9),
+                time: 1. / 60.,
+                ..ModelerInput::default()
+            })
+            .unwrap();
+        reused
+            .update(ModelerInput {
+                event_type: ModelerInputEventType::Up,
+                pos: (500.2, -499.8),
+                time: 2. / 60.,
+                ..ModelerInput::default()
+            })
+            .unwrap();
+
+        let delta_time = 1. / 50.;
+        let mut time = 10.0;
+        let down = ModelerInput {
+            event_type: ModelerInputEventType::Down,
+            pos: (-8.0, -10.0),
+            time,
+            ..ModelerInput::default()
+        };
+        assert_eq!(
+            fresh.update(down.clone()).unwrap(),
+            reused.update(down).unwrap()
+        );
+
+        time += delta_time;
+        let first_move = ModelerInput {
+            event_type: ModelerInputEventType::Move,
+            pos: (-9.0, -7.0),
+            time,
+            ..ModelerInput::default()
+        };
+        assert_eq!(
+            fresh.update(first_move.clone()).unwrap(),
+            reused.update(first_move).unwrap()
+        );
     }
 
     #[test]
@@ -2210,6 +4312,9 @@ mod tests {
             pos: (0.0, 0.0),
             time: time,
             pressure: 0.5,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(res1.is_ok());
         assert!(compare_results(
@@ -2231,82 +4336,103 @@ mod tests {
             pos: (0.0, 0.5),
             time: time,
             pressure: 0.4,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(res2.is_ok());
         assert!(compare_results(
             res2.unwrap(),
             vec![
-                ModelerResult {
-                    pos: (0.0, 0.0736),
-                    velocity: (0.0, 7.3636),
-                    acceleration: (0.0, 736.3636),
-                    time: 3.0100,
-                    pressure: 0.4853
-                },
-                ModelerResult {
-                    pos: (0.0, 0.2198),
-                    velocity: (0.0, 14.6202),
-                    acceleration: (0.0, 725.6529),
-                    time: 3.0200,
-                    pressure: 0.4560
-                },
-            ]
+                            ModelerResult {
+                                pos: (0.0, 0.0736),
+                                velocity: (0.0, 7.3636),
+                                acceleration: (0.0, 736.3636),
+                                time: 3.0100,
+                                pressure: 0.4853,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.0, 0.2198),
+                                velocity: (0.0, 14.6202),
+                                acceleration: (0.0, 725.6529),
+                                time: 3.0200,
+                                pressure: 0.4560,
+                                tilt: None,
+                                orientation: None,
+            },
+                        ]
         ));
 
         assert!(engine.predict().is_ok());
         assert!(compare_results(
             engine.predict().unwrap(),
             vec![
-                ModelerResult {
-                    pos: (0.0, 0.3823),
-                    velocity: (0.0, 11.3709),
-                    acceleration: (0.0, -227.4474),
-                    time: 3.0343,
-                    pressure: 0.4235
-                },
-                ModelerResult {
-                    pos: (0.0, 0.4484),
-                    velocity: (0.0, 4.6285),
-                    acceleration: (0.0, -471.9660),
-                    time: 3.0486,
-                    pressure: 0.4103
-                },
-                ModelerResult {
-                    pos: (0.0, 0.4775),
-                    velocity: (0.0, 2.0389),
-                    acceleration: (0.0, -181.2747),
-                    time: 3.0629,
-                    pressure: 0.4045
-                },
-                ModelerResult {
-                    pos: (0.0, 0.4902),
-                    velocity: (0.0, 0.8873),
-                    acceleration: (0.0, -80.6136),
-                    time: 3.0771,
-                    pressure: 0.4020
-                },
-                ModelerResult {
-                    pos: (0.0, 0.4957),
-                    velocity: (0.0, 0.3868),
-                    acceleration: (0.0, -35.0318),
-                    time: 3.0914,
-                    pressure: 0.4009
-                },
-                ModelerResult {
-                    pos: (0.0, 0.4981),
-                    velocity: (0.0, 0.1686),
-                    acceleration: (0.0, -15.2760),
-                    time: 3.1057,
-                    pressure: 0.4004
-                },
-                ModelerResult {
-                    pos: (0.0, 0.4992),
-                    velocity: (0.0, 0.0735),
-                    acceleration: (0.0, -6.6579),
-                    time: 3.1200,
-                    pressure: 0.4002
-                },
-            ]
+                            ModelerResult {
+                                pos: (0.0, 0.3823),
+                                velocity: (0.0, 11.3709),
+                                acceleration: (0.0, -227.4474),
+                                time: 3.0343,
+                                pressure: 0.4235,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.0, 0.4484),
+                                velocity: (0.0, 4.6285),
+                                acceleration: (0.0, -471.9660),
+                                time: 3.0486,
+                                pressure: 0.4103,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.0, 0.4775),
+                                velocity: (0.0, 2.0389),
+                                acceleration: (0.0, -181.2747),
+                                time: 3.0629,
+                                pressure: 0.4045,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.0, 0.4902),
+                                velocity: (0.0, 0.8873),
+                                acceleration: (0.0, -80.6136),
+                                time: 3.0771,
+                                pressure: 0.4020,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.0, 0.4957),
+                                velocity: (0.0, 0.3868),
+                                acceleration: (0.0, -35.0318),
+                                time: 3.0914,
+                                pressure: 0.4009,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.0, 0.4981),
+                                velocity: (0.0, 0.1686),
+                                acceleration: (0.0, -15.2760),
+                                time: 3.1057,
+                                pressure: 0.4004,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.0, 0.4992),
+                                velocity: (0.0, 0.0735),
+                                acceleration: (0.0, -6.6579),
+                                time: 3.1200,
+                                pressure: 0.4002,
+                                tilt: None,
+                                orientation: None,
+            },
+                        ]
         ));
         time += delta_time;
 
@@ -2315,82 +4441,103 @@ mod tests {
             time: time,
             pressure: 0.3,
             event_type: ModelerInputEventType::Move,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(res3.is_ok());
         assert!(compare_results(
             res3.unwrap(),
             vec![
-                ModelerResult {
-                    pos: (0.0295, 0.4169),
-                    velocity: (2.9455, 19.7093),
-                    acceleration: (294.5455, 508.9161),
-                    time: 3.0300,
-                    pressure: 0.4166
-                },
-                ModelerResult {
-                    pos: (0.0879, 0.6439),
-                    velocity: (5.8481, 22.6926),
-                    acceleration: (290.2612, 298.3311),
-                    time: 3.0400,
-                    pressure: 0.3691
-                },
-            ]
+                            ModelerResult {
+                                pos: (0.0295, 0.4169),
+                                velocity: (2.9455, 19.7093),
+                                acceleration: (294.5455, 508.9161),
+                                time: 3.0300,
+                                pressure: 0.4166,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.0879, 0.6439),
+                                velocity: (5.8481, 22.6926),
+                                acceleration: (290.2612, 298.3311),
+                                time: 3.0400,
+                                pressure: 0.3691,
+                                tilt: None,
+                                orientation: None,
+            },
+                        ]
         ));
 
         assert!(engine.predict().is_ok());
         assert!(compare_results(
             engine.predict().unwrap(),
             vec![
-                ModelerResult {
-                    pos: (0.1529, 0.8487),
-                    velocity: (4.5484, 14.3374),
-                    acceleration: (-90.9790, -584.8687),
-                    time: 3.0543,
-                    pressure: 0.3293
-                },
-                ModelerResult {
-                    pos: (0.1794, 0.9338),
-                    velocity: (1.8514, 5.9577),
-                    acceleration: (-188.7864, -586.5760),
-                    time: 3.0686,
-                    pressure: 0.3128
-                },
-                ModelerResult {
-                    pos: (0.1910, 0.9712),
-                    velocity: (0.8156, 2.6159),
-                    acceleration: (-72.5099, -233.9289),
-                    time: 3.0829,
-                    pressure: 0.3056
-                },
-                ModelerResult {
-                    pos: (0.1961, 0.9874),
-                    velocity: (0.3549, 1.1389),
-                    acceleration: (-32.2455, -103.3868),
-                    time: 3.0971,
-                    pressure: 0.3024
-                },
-                ModelerResult {
-                    pos: (0.1983, 0.9945),
-                    velocity: (0.1547, 0.4965),
-                    acceleration: (-14.0127, -44.9693),
-                    time: 3.1114,
-                    pressure: 0.3011
-                },
-                ModelerResult {
-                    pos: (0.1993, 0.9976),
-                    velocity: (0.0674, 0.2164),
-                    acceleration: (-6.1104, -19.6068),
-                    time: 3.1257,
-                    pressure: 0.3005
-                },
-                ModelerResult {
-                    pos: (0.1997, 0.9990),
-                    velocity: (0.0294, 0.0943),
-                    acceleration: (-2.6631, -8.5455),
-                    time: 3.1400,
-                    pressure: 0.3002
-                },
-            ]
+                            ModelerResult {
+                                pos: (0.1529, 0.8487),
+                                velocity: (4.5484, 14.3374),
+                                acceleration: (-90.9790, -584.8687),
+                                time: 3.0543,
+                                pressure: 0.3293,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.1794, 0.9338),
+                                velocity: (1.8514, 5.9577),
+                                acceleration: (-188.7864, -586.5760),
+                                time: 3.0686,
+                                pressure: 0.3128,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.1910, 0.9712),
+                                velocity: (0.8156, 2.6159),
+                                acceleration: (-72.5099, -233.9289),
+                                time: 3.0829,
+                                pressure: 0.3056,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.1961, 0.9874),
+                                velocity: (0.3549, 1.1389),
+                                acceleration: (-32.2455, -103.3868),
+                                time: 3.0971,
+                                pressure: 0.3024,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.1983, 0.9945),
+                                velocity: (0.1547, 0.4965),
+                                acceleration: (-14.0127, -44.9693),
+                                time: 3.1114,
+                                pressure: 0.3011,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.1993, 0.9976),
+                                velocity: (0.0674, 0.2164),
+                                acceleration: (-6.1104, -19.6068),
+                                time: 3.1257,
+                                pressure: 0.3005,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.1997, 0.9990),
+                                velocity: (0.0294, 0.0943),
+                                acceleration: (-2.6631, -8.5455),
+                                time: 3.1400,
+                                pressure: 0.3002,
+                                tilt: None,
+                                orientation: None,
+            },
+                        ]
         ));
 
         time += delta_time;
@@ -2399,81 +4546,102 @@ mod tests {
             pos: (0.4, 1.4),
             time: time,
             pressure: 0.2,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(res4.is_ok());
         assert!(compare_results(
             res4.unwrap(),
             vec![
-                ModelerResult {
-                    pos: (0.1668, 0.8712),
-                    velocity: (7.8837, 22.7349),
-                    acceleration: (203.5665, 4.2224),
-                    time: 3.0500,
-                    pressure: 0.3245
-                },
-                ModelerResult {
-                    pos: (0.2575, 1.0906),
-                    velocity: (9.0771, 21.9411),
-                    acceleration: (119.3324, -79.3721),
-                    time: 3.0600,
-                    pressure: 0.2761
-                },
-            ]
+                            ModelerResult {
+                                pos: (0.1668, 0.8712),
+                                velocity: (7.8837, 22.7349),
+                                acceleration: (203.5665, 4.2224),
+                                time: 3.0500,
+                                pressure: 0.3245,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.2575, 1.0906),
+                                velocity: (9.0771, 21.9411),
+                                acceleration: (119.3324, -79.3721),
+                                time: 3.0600,
+                                pressure: 0.2761,
+                                tilt: None,
+                                orientation: None,
+            },
+                        ]
         ));
         assert!(engine.predict().is_ok());
         assert!(compare_results(
             engine.predict().unwrap(),
             vec![
-                ModelerResult {
-                    pos: (0.3395, 1.2676),
-                    velocity: (5.7349, 12.3913),
-                    acceleration: (-233.9475, -668.4906),
-                    time: 3.0743,
-                    pressure: 0.2325
-                },
-                ModelerResult {
-                    pos: (0.3735, 1.3421),
-                    velocity: (2.3831, 5.2156),
-                    acceleration: (-234.6304, -502.2992),
-                    time: 3.0886,
-                    pressure: 0.2142
-                },
-                ModelerResult {
-                    pos: (0.3885, 1.3748),
-                    velocity: (1.0463, 2.2854),
-                    acceleration: (-93.5716, -205.1091),
-                    time: 3.1029,
-                    pressure: 0.2062
-                },
-                ModelerResult {
-                    pos: (0.3950, 1.3890),
-                    velocity: (0.4556, 0.9954),
-                    acceleration: (-41.3547, -90.3064),
-                    time: 3.1171,
-                    pressure: 0.2027
-                },
-                ModelerResult {
-                    pos: (0.3978, 1.3952),
-                    velocity: (0.1986, 0.4339),
-                    acceleration: (-17.9877, -39.3021),
-                    time: 3.1314,
-                    pressure: 0.2012
-                },
-                ModelerResult {
-                    pos: (0.3990, 1.3979),
-                    velocity: (0.0866, 0.1891),
-                    acceleration: (-7.8428, -17.1346),
-                    time: 3.1457,
-                    pressure: 0.2005
-                },
-                ModelerResult {
-                    pos: (0.3996, 1.3991),
-                    velocity: (0.0377, 0.0824),
-                    acceleration: (-3.4182, -7.4680),
-                    time: 3.1600,
-                    pressure: 0.2002
-                },
-            ]
+                            ModelerResult {
+                                pos: (0.3395, 1.2676),
+                                velocity: (5.7349, 12.3913),
+                                acceleration: (-233.9475, -668.4906),
+                                time: 3.0743,
+                                pressure: 0.2325,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.3735, 1.3421),
+                                velocity: (2.3831, 5.2156),
+                                acceleration: (-234.6304, -502.2992),
+                                time: 3.0886,
+                                pressure: 0.2142,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.3885, 1.3748),
+                                velocity: (1.0463, 2.2854),
+                                acceleration: (-93.5716, -205.1091),
+                                time: 3.1029,
+                                pressure: 0.2062,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.3950, 1.3890),
+                                velocity: (0.4556, 0.9954),
+                                acceleration: (-41.3547, -90.3064),
+                                time: 3.1171,
+                                pressure: 0.2027,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.3978, 1.3952),
+                                velocity: (0.1986, 0.4339),
+                                acceleration: (-17.9877, -39.3021),
+                                time: 3.1314,
+                                pressure: 0.2012,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.3990, 1.3979),
+                                velocity: (0.0866, 0.1891),
+                                acceleration: (-7.8428, -17.1346),
+                                time: 3.1457,
+                                pressure: 0.2005,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.3996, 1.3991),
+                                velocity: (0.0377, 0.0824),
+                                acceleration: (-3.4182, -7.4680),
+                                time: 3.1600,
+                                pressure: 0.2002,
+                                tilt: None,
+                                orientation: None,
+            },
+                        ]
         ));
         time += delta_time;
         let res5 = engine.update(ModelerInput {
@@ -2481,75 +4649,96 @@ mod tests {
             pos: (0.7, 1.7),
             pressure: 0.1,
             time: time,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(res5.is_ok());
         assert!(compare_results(
             res5.unwrap(),
             vec![
-                ModelerResult {
-                    pos: (0.3691, 1.2874),
-                    velocity: (11.1558, 19.6744),
-                    acceleration: (207.8707, -226.6725),
-                    time: 3.0700,
-                    pressure: 0.2256
-                },
-                ModelerResult {
-                    pos: (0.4978, 1.4640),
-                    velocity: (12.8701, 17.6629),
-                    acceleration: (171.4340, -201.1508),
-                    time: 3.0800,
-                    pressure: 0.1730
-                },
-                ModelerResult {
-                    pos: (0.6141, 1.5986),
-                    velocity: (8.1404, 9.4261),
-                    acceleration: (-331.0815, -576.5752),
-                    time: 3.0943,
-                    pressure: 0.1312
-                },
-                ModelerResult {
-                    pos: (0.6624, 1.6557),
-                    velocity: (3.3822, 3.9953),
-                    acceleration: (-333.0701, -380.1579),
-                    time: 3.1086,
-                    pressure: 0.1136
-                },
-                ModelerResult {
-                    pos: (0.6836, 1.6807),
-                    velocity: (1.4851, 1.7488),
-                    acceleration: (-132.8005, -157.2520),
-                    time: 3.1229,
-                    pressure: 0.1059
-                },
-                ModelerResult {
-                    pos: (0.6929, 1.6916),
-                    velocity: (0.6466, 0.7618),
-                    acceleration: (-58.6943, -69.0946),
-                    time: 3.1371,
-                    pressure: 0.1026
-                },
-                ModelerResult {
-                    pos: (0.6969, 1.6963),
-                    velocity: (0.2819, 0.3321),
-                    acceleration: (-25.5298, -30.0794),
-                    time: 3.1514,
-                    pressure: 0.1011
-                },
-                ModelerResult {
-                    pos: (0.6986, 1.6984),
-                    velocity: (0.1229, 0.1447),
-                    acceleration: (-11.1311, -13.1133),
-                    time: 3.1657,
-                    pressure: 0.1005
-                },
-                ModelerResult {
-                    pos: (0.6994, 1.6993),
-                    velocity: (0.0535, 0.0631),
-                    acceleration: (-4.8514, -5.7153),
-                    time: 3.1800,
-                    pressure: 0.1002
-                },
-            ]
+                            ModelerResult {
+                                pos: (0.3691, 1.2874),
+                                velocity: (11.1558, 19.6744),
+                                acceleration: (207.8707, -226.6725),
+                                time: 3.0700,
+                                pressure: 0.2256,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.4978, 1.4640),
+                                velocity: (12.8701, 17.6629),
+                                acceleration: (171.4340, -201.1508),
+                                time: 3.0800,
+                                pressure: 0.1730,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.6141, 1.5986),
+                                velocity: (8.1404, 9.4261),
+                                acceleration: (-331.0815, -576.5752),
+                                time: 3.0943,
+                                pressure: 0.1312,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.6624, 1.6557),
+                                velocity: (3.3822, 3.9953),
+                                acceleration: (-333.0701, -380.1579),
+                                time: 3.1086,
+                                pressure: 0.1136,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.6836, 1.6807),
+                                velocity: (1.4851, 1.7488),
+                                acceleration: (-132.8005, -157.2520),
+                                time: 3.1229,
+                                pressure: 0.1059,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.6929, 1.6916),
+                                velocity: (0.6466, 0.7618),
+                                acceleration: (-58.6943, -69.0946),
+                                time: 3.1371,
+                                pressure: 0.1026,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.6969, 1.6963),
+                                velocity: (0.2819, 0.3321),
+                                acceleration: (-25.5298, -30.0794),
+                                time: 3.1514,
+                                pressure: 0.1011,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.6986, 1.6984),
+                                velocity: (0.1229, 0.1447),
+                                acceleration: (-11.1311, -13.1133),
+                                time: 3.1657,
+                                pressure: 0.1005,
+                                tilt: None,
+                                orientation: None,
+            },
+                            ModelerResult {
+                                pos: (0.6994, 1.6993),
+                                velocity: (0.0535, 0.0631),
+                                acceleration: (-4.8514, -5.7153),
+                                time: 3.1800,
+                                pressure: 0.1002,
+                                tilt: None,
+                                orientation: None,
+            },
+                        ]
         ));
         assert!(engine.predict().is_err());
     }
@@ -2565,6 +4754,9 @@ mod tests {
             pos: (5.0, 5.0),
             time: time,
             pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(res1.is_ok());
         assert!(compare_results(
@@ -2581,6 +4773,9 @@ mod tests {
             pos: (5.0, 5.0),
             pressure: 1.0,
             time: time,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(res2.is_ok());
         assert!(compare_results(
@@ -2596,6 +4791,9 @@ mod tests {
             pos: (5.0, 5.0),
             time: time,
             pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
         });
         assert!(res3.is_ok());
         assert!(compare_results(
@@ -2609,6 +4807,62 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn a_looser_end_of_stroke_stopping_distance_emits_a_shorter_catch_up_tail() {
+        // `sampling_end_of_stroke_stopping_distance`/`sampling_end_of_stroke_max_iterations`
+        // already let callers trade tail length against convergence precision (this is not a
+        // hardcoded termination); a loose stopping distance should stop the `Up` catch-up much
+        // sooner than a tight one fed the same stroke.
+        let down = ModelerInput {
+            event_type: ModelerInputEventType::Down,
+            pos: (0.0, 0.0),
+            time: 0.0,
+            pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
+        };
+        let mv = ModelerInput {
+            event_type: ModelerInputEventType::Move,
+            pos: (10.0, 0.0),
+            time: 1. / 60.,
+            pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
+        };
+        let up = ModelerInput {
+            event_type: ModelerInputEventType::Up,
+            pos: (10.0, 0.0),
+            time: 2. / 60.,
+            pressure: 1.0,
+
+            tilt: None,
+            orientation: None,
+        };
+
+        let loose_params = ModelerParamsBuilder::new()
+            .sampling_end_of_stroke_stopping_distance(1.0)
+            .build()
+            .unwrap();
+        let mut loose = StrokeModeler::new(loose_params).unwrap();
+        loose.update(down.clone()).unwrap();
+        loose.update(mv.clone()).unwrap();
+        let loose_up = loose.update(up.clone()).unwrap();
+
+        let tight_params = ModelerParamsBuilder::new()
+            .sampling_end_of_stroke_stopping_distance(0.0001)
+            .sampling_end_of_stroke_max_iterations(999)
+            .build()
+            .unwrap();
+        let mut tight = StrokeModeler::new(tight_params).unwrap();
+        tight.update(down).unwrap();
+        tight.update(mv).unwrap();
+        let tight_up = tight.update(up).unwrap();
+
+        assert!(loose_up.len() < tight_up.len());
+    }
+
     // needed ? we already catch this in the rust code
     // #[test]
     // fn far_apart_times_move() {
@@ -1,29 +1,50 @@
 #[derive(Debug, Clone, thiserror::Error)]
 #[non_exhaustive]
 pub enum ElementError {
-    #[error("A duplicate element is sent to the modeler")]
-    Duplicate,
-    #[error("A sent element has a time earlier than the previous one")]
-    NegativeTimeDelta,
+    /// the element at `index` (timestamp `time`) repeats the previous element's time
+    #[error("element {index} at t={time} is a duplicate of the previous element")]
+    Duplicate { index: usize, time: f64 },
+    /// the element at `index` (timestamp `time`) has a time earlier than `previous_time`
+    #[error(
+        "element {index} at t={time} has a time before the previous element (t={previous_time})"
+    )]
+    NegativeTimeDelta {
+        index: usize,
+        time: f64,
+        previous_time: f64,
+    },
     #[error("Sent element order is incorrect")]
     Order {
         #[from]
         src: ElementOrderError,
     },
-    #[error("Sent element's time is too far apart from the previous one.")]
-    TooFarApart,
+    /// the element at `index` (timestamp `time`) is more than `max_gap` after `previous_time`
+    #[error(
+        "element {index} at t={time} is {gap} after previous (max {max_gap})",
+        gap = time - previous_time
+    )]
+    TooFarApart {
+        index: usize,
+        time: f64,
+        previous_time: f64,
+        max_gap: f64,
+    },
 }
 
 #[derive(Debug, Clone, thiserror::Error)]
 #[non_exhaustive]
 #[allow(clippy::enum_variant_names)]
 pub enum ElementOrderError {
-    #[error("Down Event is not the first or occured after a different event")]
-    UnexpectedDown,
-    #[error("Move event occured before a initial down event")]
-    UnexpectedMove,
-    #[error("No other event occured before an up event")]
-    UnexpectedUp,
+    /// the element at `index` (timestamp `time`) is a Down event that is not the first, or
+    /// that occured after a different event
+    #[error("element {index} at t={time}: Down event is not the first or occured after a different event")]
+    UnexpectedDown { index: usize, time: f64 },
+    /// the element at `index` (timestamp `time`) is a Move event that occured before any Down event
+    #[error("element {index} at t={time}: Move event occured before a initial down event")]
+    UnexpectedMove { index: usize, time: f64 },
+    /// the element at `index` (timestamp `time`) is an Up event with no prior event in the stroke
+    #[error("element {index} at t={time}: no other event occured before an up event")]
+    UnexpectedUp { index: usize, time: f64 },
 }
 
 #[derive(Debug, Clone, thiserror::Error)]
@@ -34,4 +55,55 @@ pub enum ModelerError {
         #[from]
         src: ElementError,
     },
+    /// the input's time is earlier than the previous input's time. Only raised by the
+    /// lower-level, non-element-stream position-modeling path; the element stream itself
+    /// (`StrokeModeler::update`/`update_to`) reports this via [`ElementError::NegativeTimeDelta`]
+    /// instead, which also carries the offending index.
+    #[error("input time ({cur}) is earlier than the previous input's time ({prev})")]
+    NonMonotonicTime { prev: f64, cur: f64 },
+    /// the input's pressure is negative
+    #[error("input pressure is negative")]
+    NegativePressure,
+    /// a position, time, pressure, tilt or orientation value is NaN or infinite
+    #[error("input position, time, pressure, tilt or orientation is not finite")]
+    NonFiniteInput,
+    /// the requested number of upsampling steps is not positive
+    #[error("number of steps ({0}) must be positive")]
+    InvalidStepCount(i32),
+    /// the end of a linear path is not strictly after its start
+    #[error("end_time ({end}) must be strictly greater than start_time ({start})")]
+    InvalidTimeRange { start: f64, end: f64 },
+    /// a pressure, tilt or orientation value lies outside its valid range
+    #[error("`{field}` value ({value}) is outside its valid range")]
+    OutOfRange { field: &'static str, value: f64 },
+    /// (only reachable with the `fixed-buffer` feature) a fixed-capacity output buffer does
+    /// not have room for another result
+    #[error("result buffer (capacity {capacity}) is full")]
+    BufferOverflow { capacity: usize },
+}
+
+impl ModelerError {
+    /// Returns `true` for variants describing a single malformed input: a duplicate or
+    /// too-far-apart element in the submitted stream
+    /// ([`ElementError::Duplicate`]/[`ElementError::TooFarApart`]), a non-finite or
+    /// out-of-range position/pressure/tilt/orientation, or a non-monotonic timestamp (either
+    /// [`ElementError::NegativeTimeDelta`] within the element stream or, outside it,
+    /// [`ModelerError::NonMonotonicTime`]) — the caller can drop that one sample and keep
+    /// feeding the stroke. Returns `false` for variants describing misuse of the
+    /// `update`/`update_to` API itself: an out-of-order element
+    /// ([`ElementError::Order`]/[`ElementOrderError`], e.g. a `Move` before any `Down`), an
+    /// invalid step count or time range, or a full output buffer — these indicate a
+    /// programming error rather than a glitch in the input stream.
+    pub fn is_input_glitch(&self) -> bool {
+        match self {
+            ModelerError::Element { src } => !matches!(src, ElementError::Order { .. }),
+            ModelerError::NonMonotonicTime { .. }
+            | ModelerError::NegativePressure
+            | ModelerError::NonFiniteInput
+            | ModelerError::OutOfRange { .. } => true,
+            ModelerError::InvalidStepCount(_)
+            | ModelerError::InvalidTimeRange { .. }
+            | ModelerError::BufferOverflow { .. } => false,
+        }
+    }
 }
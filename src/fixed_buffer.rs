@@ -0,0 +1,56 @@
+use crate::{ModelerError, ModelerResult};
+
+/// Fixed-capacity, non-allocating alternative to `Vec<ModelerResult>`, behind the
+/// `fixed-buffer` feature: [crate::StrokeModeler::update_into] writes at most `N` results
+/// into a buffer of this type and reports overflow via [ModelerError::BufferOverflow]
+/// instead of growing a heap allocation.
+///
+/// This covers the *output* of a single `update` call; it is an adapter over the existing
+/// engine internals rather than a full no-alloc rewrite of the modeler's internal state, and
+/// (despite the name this feature used to go by) does not make the crate itself `no_std` —
+/// the rest of the modeling pipeline still depends on `std`.
+pub struct FixedResultBuffer<const N: usize> {
+    items: [ModelerResult; N],
+    len: usize,
+}
+
+impl<const N: usize> FixedResultBuffer<N> {
+    pub fn new() -> Self {
+        Self {
+            items: core::array::from_fn(|_| ModelerResult::default()),
+            len: 0,
+        }
+    }
+
+    /// Appends `item`, or returns [ModelerError::BufferOverflow] if the buffer is full
+    pub fn push(&mut self, item: ModelerResult) -> Result<(), ModelerError> {
+        if self.len >= N {
+            return Err(ModelerError::BufferOverflow { capacity: N });
+        }
+        self.items[self.len] = item;
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn as_slice(&self) -> &[ModelerResult] {
+        &self.items[..self.len]
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> Default for FixedResultBuffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
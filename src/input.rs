@@ -1,5 +1,8 @@
+use crate::scalar::Scalar;
+
 /// modeler Input event Type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 #[allow(unused)]
 pub enum ModelerInputEventType {
@@ -13,13 +16,19 @@ pub enum ModelerInputEventType {
 
 /// struct holding all information for input event
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModelerInput {
     pub event_type: ModelerInputEventType,
     pub pos: (f64, f64),
     pub time: f64,
+    /// normalized pressure, should be in the range `[0.0, 1.0]`
     pub pressure: f64,
-    // tilt and orientation are optional parameters, so we remove them here to
-    // make our lives easier
+    /// stylus tilt angle, in radians, should be in the range `[0.0, PI / 2.0]`.
+    /// `None` when the stylus does not report it
+    pub tilt: Option<f64>,
+    /// stylus orientation angle, in radians, should be in the range `[0.0, 2.0 * PI)`.
+    /// `None` when the stylus does not report it
+    pub orientation: Option<f64>,
 }
 
 impl Default for ModelerInput {
@@ -29,6 +38,45 @@ impl Default for ModelerInput {
             pos: (0.0, 0.0),
             time: 0.0,
             pressure: 1.0,
+            tilt: None,
+            orientation: None,
         }
     }
 }
+
+#[cfg(feature = "glam")]
+impl ModelerInput {
+    /// [Self::pos] as a [glam::Vec2], for callers already working in `glam`. Lossy the same
+    /// way the rest of the pipeline is: `pos` is stored as `(f64, f64)` but every downstream
+    /// computation narrows it to `f32`, so this narrows too rather than introducing a second,
+    /// higher-precision code path.
+    pub fn pos_vec2(&self) -> glam::Vec2 {
+        glam::Vec2::new(self.pos.0 as f32, self.pos.1 as f32)
+    }
+
+    /// Sets [Self::pos] from a [glam::Vec2].
+    pub fn set_pos_vec2(&mut self, pos: glam::Vec2) {
+        self.pos = (pos.x as f64, pos.y as f64);
+    }
+}
+
+impl ModelerInput {
+    /// [Self::pos] converted to any [Scalar], for callers whose own geometry types use a
+    /// different float representation than this crate's `f64`. A value for which
+    /// `F::from_f64` returns `None` becomes `F::zero()`; an `f64` magnitude overflowing a float
+    /// `F` converts to infinity rather than to zero.
+    pub fn pos_scalar<F: Scalar>(&self) -> (F, F) {
+        (
+            F::from_f64(self.pos.0).unwrap_or_else(F::zero),
+            F::from_f64(self.pos.1).unwrap_or_else(F::zero),
+        )
+    }
+
+    /// Sets [Self::pos] from any [Scalar].
+    pub fn set_pos_scalar<F: Scalar>(&mut self, pos: (F, F)) {
+        self.pos = (
+            pos.0.to_f64().unwrap_or_default(),
+            pos.1.to_f64().unwrap_or_default(),
+        );
+    }
+}
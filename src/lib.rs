@@ -1,10 +1,15 @@
 // Modules
+mod bezier;
 mod engine;
 mod error;
+#[cfg(feature = "fixed-buffer")]
+mod fixed_buffer;
 mod input;
 mod params;
 mod position_modeler;
+mod prediction;
 mod results;
+mod scalar;
 mod state_modeler;
 mod utils;
 
@@ -12,9 +17,26 @@ mod utils;
 extern crate approx;
 
 // Re-Exports
+pub use bezier::{fit_cubic_beziers, CubicBezier};
+pub use engine::ModelerState;
+pub use engine::ResultSink;
 pub use engine::StrokeModeler;
+pub use error::ElementError;
+pub use error::ElementOrderError;
 pub use error::ModelerError;
+#[cfg(feature = "fixed-buffer")]
+pub use fixed_buffer::FixedResultBuffer;
 pub use input::ModelerInput;
 pub use input::ModelerInputEventType;
+pub use params::IntegrationMethod;
 pub use params::ModelerParams;
+pub use params::ModelerParamsBuilder;
+pub use params::PredictionParams;
+pub use params::WobbleKernel;
+pub use params::WobbleSmootherMode;
+pub use prediction::KalmanPredictorParams;
+pub use results::ApproxEq;
+pub(crate) use results::ModelerPartial;
 pub use results::ModelerResult;
+pub use scalar::Scalar;
+pub use state_modeler::{ExtrapolationMode, StylusStateConfig};
@@ -1,5 +1,10 @@
+use crate::prediction::KalmanPredictorParams;
+use crate::scalar::Scalar;
+use crate::state_modeler::StylusStateConfig;
+
 /// all parameters for the modeler
 #[derive(Debug, Clone, PartialEq, PartialOrd, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModelerParams {
     /// these parameters are used to apply smoothing to the input to reduce
     /// wobble in the prediction
@@ -28,9 +33,18 @@ pub struct ModelerParams {
     /// no smoothing is applied
     ///
     /// Good starting points are 2 - 3 % of the expected speed of the inputs
-    /// Should be positive and the speed floor smaller than the ceiling
+    /// Should be positive and the speed floor no greater than the ceiling.
+    /// Setting floor == ceiling turns the smoothing into a hard threshold:
+    /// full smoothing below that speed, none above it.
     pub wobble_smoother_speed_floor: f32,
     pub wobble_smoother_speed_ceiling: f32,
+    /// which averaging strategy the wobble smoother uses to estimate a low-frequency
+    /// position/speed from recent raw input
+    pub wobble_smoother_mode: WobbleSmootherMode,
+    /// how samples within the [WobbleSmootherMode::Window] averaging window are weighted;
+    /// has no effect under [WobbleSmootherMode::Ewma], which already decays older samples
+    /// via its own `alpha`
+    pub wobble_kernel: WobbleKernel,
     /// The mass of the "weight" being pulled along the path, multiplied by the spring constant.
     ///
     /// Should be positive
@@ -39,6 +53,21 @@ pub struct ModelerParams {
     ///
     /// Should be positive
     pub position_modeler_drag_constant: f32,
+    /// which numerical integration scheme [crate::StrokeModeler] uses to advance the
+    /// spring-mass-damper's velocity and position over each sub-step
+    pub position_modeler_integration_method: IntegrationMethod,
+    /// Safety factor applied to the CFL-style stability bound used internally to decide when
+    /// a single update step's `delta_time` is too large and must be subdivided into
+    /// sub-steps. The raw bound keeps each sub-step below both the oscillation period
+    /// `2*PI*sqrt(position_modeler_spring_mass_constant)` and the drag relaxation time
+    /// `1/position_modeler_drag_constant`; this factor scales that bound down for extra
+    /// margin.
+    ///
+    /// Defaults to 1.0 (the raw bound, no extra margin); lower it for additional
+    /// headroom against stiffer springs or longer input gaps than expected.
+    ///
+    /// Should be in the range (0.0, 1.0]
+    pub position_modeler_cfl_safety_factor: f32,
     /// The minimum number of modeled inputs to output per unit time. If inputs are received at a lower rate,
     /// they will be upsampled to produce output of atleast [ModelerParams::sampling_min_output_rate].
     /// If inputs are received at a higher rate, the output rate will match the input rate.
@@ -67,68 +96,191 @@ pub struct ModelerParams {
     ///
     /// Should be strictly positive
     pub sampling_max_outputs_per_call: usize,
+    /// The maximum time gap (in seconds) between one input and the next that
+    /// [StrokeModeler::update]/[StrokeModeler::update_to] will upsample. An input arriving
+    /// later than this after the previous one is rejected with
+    /// [ElementError::TooFarApart](crate::ElementError::TooFarApart) instead of being
+    /// expanded into a very large number of interpolated outputs (a stale connection or a
+    /// paused/backgrounded app are the usual causes of such a gap).
+    ///
+    /// Should be strictly positive
+    pub sampling_max_time_gap: f64,
     /// the maximum number of raw inputs to look at when
     /// searching for the nearest states when interpolating
     ///
     /// Should be strictly positive
     pub stylus_state_modeler_max_input_samples: usize,
+    /// the neutral pressure/tilt/orientation values reported before any input has been
+    /// recorded, and how positions beyond the start or end of the recorded stroke are
+    /// resolved
+    pub stylus_state_config: StylusStateConfig,
+    /// which strategy (if any) [StrokeModeler::predict] uses to extrapolate the
+    /// trailing motion of the stroke, to compensate for display latency
+    pub prediction: PredictionParams,
+    /// whether [StrokeModeler::update]/[StrokeModeler::update_to] check each input's position,
+    /// time, pressure, tilt and orientation for `NaN`/infinite values and out-of-range
+    /// pressure/tilt/orientation before processing it.
+    ///
+    /// Defaults to `true`. Input ordering (non-monotonic time, duplicate timestamps, a `Down`
+    /// while a stroke is in progress, a `Move`/`Up` before any `Down`) is always checked
+    /// regardless of this flag, since the modeling math relies on it; this only controls the
+    /// extra per-value sanity checks, which a caller that already validates upstream (e.g. a
+    /// wrapper that sanitizes OS input events) may want to skip.
+    pub strict_input_validation: bool,
+}
+
+/// Selects the averaging strategy the wobble smoother uses to estimate a low-frequency
+/// position/speed from recent raw input, before interpolating between that estimate and the
+/// raw position based on normalized speed
+/// ([ModelerParams::wobble_smoother_speed_floor]/[ModelerParams::wobble_smoother_speed_ceiling]).
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WobbleSmootherMode {
+    /// Keeps a deque of the samples within [ModelerParams::wobble_smoother_timeout] of the
+    /// latest input and averages over it. Memory use grows with the input rate.
+    Window,
+    /// Keeps a single exponentially-weighted moving average of position and speed instead of
+    /// a deque, decayed as `alpha = 1 - exp(-dt / wobble_smoother_timeout)` on each input.
+    /// Constant memory regardless of input rate, at the cost of a softer cutoff than the
+    /// windowed average.
+    Ewma,
+}
+
+/// Selects how samples in the [WobbleSmootherMode::Window] averaging window are weighted
+/// before being averaged into the smoothed position/speed estimate. In every variant, a
+/// sample's raw weight is still scaled by its `duration` (the time since the previous
+/// sample), so an uneven input rate is accounted for the same way regardless of kernel.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WobbleKernel {
+    /// Every sample in the window counts equally, weighted only by its `duration`. This is
+    /// the behavior the window averager has always had.
+    Uniform,
+    /// Samples are weighted by `max(0, 1 - |t_now - t_sample| / wobble_smoother_timeout)`, a
+    /// "hat"/triangular rolloff that linearly de-emphasizes samples as they approach the
+    /// edge of the window.
+    Triangular,
+    /// Samples are weighted by `exp(-(t_now - t_sample)^2 / (2 * sigma^2))`, with `sigma`
+    /// derived from [ModelerParams::wobble_smoother_timeout] (`timeout / 3`, so the window
+    /// edge sits at roughly 3 standard deviations). Smoother rolloff than
+    /// [WobbleKernel::Triangular], at the cost of never fully reaching zero weight.
+    Gaussian,
+}
+
+/// Selects the numerical integration scheme the position modeler uses to advance velocity
+/// and position over each sub-step of the spring-mass-damper
+/// (`a = (anchor - pos) / position_modeler_spring_mass_constant - position_modeler_drag_constant * vel`).
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IntegrationMethod {
+    /// Updates velocity from the acceleration at the start of the sub-step, then advances
+    /// position using the *already-updated* velocity. This is what the position modeler has
+    /// always done (it is semi-implicit/symplectic Euler, not plain forward Euler), kept as
+    /// the default so existing output is bit-for-bit unchanged.
+    SemiImplicitEuler,
+    /// Advances position using the velocity from *before* the sub-step, then updates velocity
+    /// from the acceleration at the start of the sub-step. Plain explicit Euler: simpler but
+    /// less stable than [IntegrationMethod::SemiImplicitEuler] at large step sizes.
+    ForwardEuler,
+    /// Fourth-order Runge-Kutta: evaluates the `(velocity, acceleration)` derivative four
+    /// times per sub-step (at the start, twice at the midpoint, and at the end) and combines
+    /// them with the classic `1/6, 1/3, 1/3, 1/6` weights, for higher accuracy than either
+    /// Euler variant at the cost of more derivative evaluations.
+    Rk4,
+}
+
+/// Selects the extrapolation strategy used by [StrokeModeler::predict]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PredictionParams {
+    /// No prediction: [StrokeModeler::predict] returns no extra points
+    Disabled,
+    /// Reuses the end-of-stroke catch-up iteration as the extrapolation heuristic
+    StrokeEnd,
+    /// Kalman-filter based forward extrapolation of the stylus trajectory
+    Kalman(KalmanPredictorParams),
 }
 
 impl ModelerParams {
     /// [ModelerParams::wobble_smoother_timeout] : 0.04,\
     /// [ModelerParams::wobble_smoother_speed_floor] : 1.31,\
     /// [ModelerParams::wobble_smoother_speed_ceiling] : 1.44,\
+    /// [ModelerParams::wobble_smoother_mode] : [WobbleSmootherMode::Window],\
+    /// [ModelerParams::wobble_kernel] : [WobbleKernel::Uniform],\
     /// [ModelerParams::position_modeler_spring_mass_constant] : 11.0 / 32400.0,\
     /// [ModelerParams::position_modeler_drag_constant] : 72.0,\
+    /// [ModelerParams::position_modeler_integration_method] : [IntegrationMethod::SemiImplicitEuler],\
+    /// [ModelerParams::position_modeler_cfl_safety_factor] : 1.0,\
     /// [ModelerParams::sampling_min_output_rate] : 180.0,\
     /// [ModelerParams::sampling_end_of_stroke_stopping_distance] : 0.001,\
     /// [ModelerParams::sampling_end_of_stroke_max_iterations] : 20,\
     /// [ModelerParams::sampling_max_outputs_per_call] : 20,\
-    /// [ModelerParams::stylus_state_modeler_max_input_samples] : 10,
+    /// [ModelerParams::sampling_max_time_gap] : 5.0,\
+    /// [ModelerParams::stylus_state_modeler_max_input_samples] : 10,\
+    /// [ModelerParams::stylus_state_config] : [StylusStateConfig::default],\
+    /// [ModelerParams::strict_input_validation] : true,
     pub fn suggested() -> Self {
         Self {
             wobble_smoother_timeout: 0.04,
             wobble_smoother_speed_floor: 1.31,
             wobble_smoother_speed_ceiling: 1.44,
+            wobble_smoother_mode: WobbleSmootherMode::Window,
+            wobble_kernel: WobbleKernel::Uniform,
             position_modeler_spring_mass_constant: 11.0 / 32400.0,
             position_modeler_drag_constant: 72.0,
+            position_modeler_integration_method: IntegrationMethod::SemiImplicitEuler,
+            position_modeler_cfl_safety_factor: 1.0,
             sampling_min_output_rate: 180.0,
             sampling_end_of_stroke_stopping_distance: 0.001,
             sampling_end_of_stroke_max_iterations: 20,
             sampling_max_outputs_per_call: 20,
+            sampling_max_time_gap: 5.0,
             stylus_state_modeler_max_input_samples: 10,
+            stylus_state_config: StylusStateConfig::default(),
+            prediction: PredictionParams::StrokeEnd,
+            strict_input_validation: true,
         }
     }
 
     /// validate the parameters as being correct, returns a error string with
     /// the reasons otherwise
     pub fn validate(self) -> Result<Self, String> {
+        if let PredictionParams::Kalman(kalman_params) = self.prediction {
+            kalman_params.validate()?;
+        }
+
         let parameter_tests = [
             self.position_modeler_spring_mass_constant > 0.0,
             self.position_modeler_drag_constant > 0.0,
+            self.position_modeler_cfl_safety_factor > 0.0,
+            self.position_modeler_cfl_safety_factor <= 1.0,
             self.sampling_min_output_rate > 0.0,
             self.sampling_end_of_stroke_stopping_distance > 0.0,
             self.sampling_end_of_stroke_max_iterations > 0,
             self.sampling_end_of_stroke_max_iterations < 1000,
             self.sampling_max_outputs_per_call > 0,
+            self.sampling_max_time_gap > 0.0,
             self.wobble_smoother_timeout > 0.0,
             self.wobble_smoother_speed_floor > 0.0,
             self.wobble_smoother_speed_ceiling > 0.0,
-            self.wobble_smoother_speed_floor < self.wobble_smoother_speed_ceiling,
+            self.wobble_smoother_speed_floor <= self.wobble_smoother_speed_ceiling,
         ];
 
         let errors = vec![
             "`position_modeler_spring_mass_constant` is not positive; ",
             "`position_modeler_drag_constant` is not positive; ",
+            "`position_modeler_cfl_safety_factor` is not positive; ",
+            "`position_modeler_cfl_safety_factor` is greater than 1.0; ",
             "`sampling_min_output_rate` is not positive; ",
             "`sampling_end_of_stroke_stopping_distance` is not positive; ",
             "`sampling_end_of_stroke_max_iterations` is not positive; ",
             "`sampling_end_of_stroke_max_iterations` is too large (>1000); ",
             "`sampling_max_outputs_per_call` is not positive; ",
+            "`sampling_max_time_gap` is not positive; ",
             "`wobble_smoother_timeout` is not positive; ",
             "`wobble_smoother_speed_floor` is not positive; ",
             "`wobble_smoother_speed_ceiling` is not positive; ",
-            "`wobble_smoother_speed_floor` should be strictly smaller than `wobble_smoother_speed_ceiling`",
+            "`wobble_smoother_speed_floor` should not be greater than `wobble_smoother_speed_ceiling`",
         ];
 
         let tests_passed = parameter_tests.iter().fold(true, |acc, x| acc & x);
@@ -148,6 +300,196 @@ impl ModelerParams {
             Err(error_acc)
         }
     }
+
+    /// Preset tuned for handwriting : this is the general-purpose tuning used by
+    /// [ModelerParams::suggested]
+    pub fn for_handwriting() -> Self {
+        Self::suggested()
+    }
+
+    /// Preset tuned for broad marker strokes : a higher wobble smoother speed floor and
+    /// ceiling (2.0 / 3.0), so wobble smoothing stays active up to faster pen speeds, and a
+    /// lower minimum output rate (120). The wobble smoothing window is unchanged
+    pub fn for_marker() -> Self {
+        Self {
+            wobble_smoother_speed_floor: 2.0,
+            wobble_smoother_speed_ceiling: 3.0,
+            sampling_min_output_rate: 120.0,
+            ..Self::suggested()
+        }
+    }
+
+    /// Preset tuned for technical drawing : a lower wobble smoother speed floor and ceiling
+    /// (0.5 / 0.75), so wobble smoothing stops at slower pen speeds, and a higher minimum
+    /// output rate (240). The wobble smoothing window is unchanged
+    pub fn for_technical_drawing() -> Self {
+        Self {
+            wobble_smoother_speed_floor: 0.5,
+            wobble_smoother_speed_ceiling: 0.75,
+            sampling_min_output_rate: 240.0,
+            ..Self::suggested()
+        }
+    }
+}
+
+/// Fluent builder for [ModelerParams], starting from [ModelerParams::suggested] and
+/// validating the accumulated parameters once, on [ModelerParamsBuilder::build]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct ModelerParamsBuilder {
+    params: ModelerParams,
+}
+
+impl Default for ModelerParamsBuilder {
+    fn default() -> Self {
+        Self {
+            params: ModelerParams::suggested(),
+        }
+    }
+}
+
+impl ModelerParamsBuilder {
+    /// starts a new builder from [ModelerParams::suggested]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn wobble_smoother_timeout(mut self, value: f64) -> Self {
+        self.params.wobble_smoother_timeout = value;
+        self
+    }
+
+    pub fn wobble_smoother_speed_floor(mut self, value: f32) -> Self {
+        self.params.wobble_smoother_speed_floor = value;
+        self
+    }
+
+    pub fn wobble_smoother_speed_ceiling(mut self, value: f32) -> Self {
+        self.params.wobble_smoother_speed_ceiling = value;
+        self
+    }
+
+    pub fn wobble_smoother_mode(mut self, value: WobbleSmootherMode) -> Self {
+        self.params.wobble_smoother_mode = value;
+        self
+    }
+
+    pub fn wobble_kernel(mut self, value: WobbleKernel) -> Self {
+        self.params.wobble_kernel = value;
+        self
+    }
+
+    pub fn position_modeler_spring_mass_constant(mut self, value: f32) -> Self {
+        self.params.position_modeler_spring_mass_constant = value;
+        self
+    }
+
+    pub fn position_modeler_drag_constant(mut self, value: f32) -> Self {
+        self.params.position_modeler_drag_constant = value;
+        self
+    }
+
+    pub fn position_modeler_integration_method(mut self, value: IntegrationMethod) -> Self {
+        self.params.position_modeler_integration_method = value;
+        self
+    }
+
+    pub fn position_modeler_cfl_safety_factor(mut self, value: f32) -> Self {
+        self.params.position_modeler_cfl_safety_factor = value;
+        self
+    }
+
+    pub fn sampling_min_output_rate(mut self, value: f64) -> Self {
+        self.params.sampling_min_output_rate = value;
+        self
+    }
+
+    pub fn sampling_end_of_stroke_stopping_distance(mut self, value: f32) -> Self {
+        self.params.sampling_end_of_stroke_stopping_distance = value;
+        self
+    }
+
+    pub fn sampling_end_of_stroke_max_iterations(mut self, value: usize) -> Self {
+        self.params.sampling_end_of_stroke_max_iterations = value;
+        self
+    }
+
+    pub fn sampling_max_outputs_per_call(mut self, value: usize) -> Self {
+        self.params.sampling_max_outputs_per_call = value;
+        self
+    }
+
+    pub fn sampling_max_time_gap(mut self, value: f64) -> Self {
+        self.params.sampling_max_time_gap = value;
+        self
+    }
+
+    pub fn stylus_state_modeler_max_input_samples(mut self, value: usize) -> Self {
+        self.params.stylus_state_modeler_max_input_samples = value;
+        self
+    }
+
+    pub fn stylus_state_config(mut self, value: StylusStateConfig) -> Self {
+        self.params.stylus_state_config = value;
+        self
+    }
+
+    pub fn prediction(mut self, value: PredictionParams) -> Self {
+        self.params.prediction = value;
+        self
+    }
+
+    pub fn strict_input_validation(mut self, value: bool) -> Self {
+        self.params.strict_input_validation = value;
+        self
+    }
+
+    /// validates the accumulated parameters and returns the built [ModelerParams],
+    /// or an error string with the reasons the parameters are invalid
+    pub fn build(self) -> Result<ModelerParams, String> {
+        self.params.validate()
+    }
+}
+
+/// [Scalar]-generic counterparts of [ModelerParamsBuilder]'s float setters, for callers whose
+/// own tuning/config values come from a numeric type other than `f32`/`f64` (e.g. a generic
+/// math library shared with the rest of their app). Each converts `value` to the field's
+/// native float type and delegates to the matching setter.
+impl ModelerParamsBuilder {
+    pub fn wobble_smoother_timeout_scalar<F: Scalar>(self, value: F) -> Self {
+        self.wobble_smoother_timeout(value.to_f64().unwrap_or_default())
+    }
+
+    pub fn wobble_smoother_speed_floor_scalar<F: Scalar>(self, value: F) -> Self {
+        self.wobble_smoother_speed_floor(value.to_f32().unwrap_or_default())
+    }
+
+    pub fn wobble_smoother_speed_ceiling_scalar<F: Scalar>(self, value: F) -> Self {
+        self.wobble_smoother_speed_ceiling(value.to_f32().unwrap_or_default())
+    }
+
+    pub fn position_modeler_spring_mass_constant_scalar<F: Scalar>(self, value: F) -> Self {
+        self.position_modeler_spring_mass_constant(value.to_f32().unwrap_or_default())
+    }
+
+    pub fn position_modeler_drag_constant_scalar<F: Scalar>(self, value: F) -> Self {
+        self.position_modeler_drag_constant(value.to_f32().unwrap_or_default())
+    }
+
+    pub fn position_modeler_cfl_safety_factor_scalar<F: Scalar>(self, value: F) -> Self {
+        self.position_modeler_cfl_safety_factor(value.to_f32().unwrap_or_default())
+    }
+
+    pub fn sampling_min_output_rate_scalar<F: Scalar>(self, value: F) -> Self {
+        self.sampling_min_output_rate(value.to_f64().unwrap_or_default())
+    }
+
+    pub fn sampling_end_of_stroke_stopping_distance_scalar<F: Scalar>(self, value: F) -> Self {
+        self.sampling_end_of_stroke_stopping_distance(value.to_f32().unwrap_or_default())
+    }
+
+    pub fn sampling_max_time_gap_scalar<F: Scalar>(self, value: F) -> Self {
+        self.sampling_max_time_gap(value.to_f64().unwrap_or_default())
+    }
 }
 
 #[cfg(test)]
@@ -160,13 +502,21 @@ mod test_params {
             wobble_smoother_timeout: -1.0,
             wobble_smoother_speed_floor: -1.0,
             wobble_smoother_speed_ceiling: -1.0,
+            wobble_smoother_mode: WobbleSmootherMode::Window,
+            wobble_kernel: WobbleKernel::Uniform,
             position_modeler_spring_mass_constant: -1.0,
             position_modeler_drag_constant: -1.0,
+            position_modeler_integration_method: IntegrationMethod::SemiImplicitEuler,
+            position_modeler_cfl_safety_factor: -1.0,
             sampling_min_output_rate: -1.0,
             sampling_end_of_stroke_stopping_distance: -1.0,
             sampling_end_of_stroke_max_iterations: 0,
             sampling_max_outputs_per_call: 0,
+            sampling_max_time_gap: -1.0,
             stylus_state_modeler_max_input_samples: 0,
+            stylus_state_config: StylusStateConfig::default(),
+            prediction: PredictionParams::StrokeEnd,
+            strict_input_validation: true,
         })
         .validate();
         match s {
@@ -174,4 +524,65 @@ mod test_params {
             Err(_) => assert!(true),
         }
     }
+
+    #[test]
+    fn equal_speed_floor_and_ceiling_is_valid() {
+        let s = ModelerParams {
+            wobble_smoother_speed_floor: 1.0,
+            wobble_smoother_speed_ceiling: 1.0,
+            ..ModelerParams::suggested()
+        }
+        .validate();
+        assert!(s.is_ok());
+    }
+
+    #[test]
+    fn builder_overrides_fields_and_validates() {
+        let params = ModelerParamsBuilder::new()
+            .sampling_min_output_rate(90.0)
+            .wobble_smoother_speed_floor(1.0)
+            .wobble_smoother_speed_ceiling(1.0)
+            .build()
+            .unwrap();
+        assert_eq!(params.sampling_min_output_rate, 90.0);
+        assert_eq!(params.wobble_smoother_speed_floor, 1.0);
+        assert_eq!(params.wobble_smoother_speed_ceiling, 1.0);
+    }
+
+    #[test]
+    fn builder_propagates_validation_errors() {
+        let result = ModelerParamsBuilder::new()
+            .sampling_min_output_rate(-1.0)
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn cfl_safety_factor_out_of_range_is_invalid() {
+        let too_low = ModelerParams {
+            position_modeler_cfl_safety_factor: 0.0,
+            ..ModelerParams::suggested()
+        }
+        .validate();
+        assert!(too_low.is_err());
+
+        let too_high = ModelerParams {
+            position_modeler_cfl_safety_factor: 1.5,
+            ..ModelerParams::suggested()
+        }
+        .validate();
+        assert!(too_high.is_err());
+    }
+
+    #[test]
+    fn presets_are_valid_and_distinct() {
+        assert!(ModelerParams::for_handwriting().validate().is_ok());
+        assert!(ModelerParams::for_marker().validate().is_ok());
+        assert!(ModelerParams::for_technical_drawing().validate().is_ok());
+
+        assert_ne!(
+            ModelerParams::for_marker().sampling_min_output_rate,
+            ModelerParams::for_technical_drawing().sampling_min_output_rate
+        );
+    }
 }
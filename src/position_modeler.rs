@@ -1,13 +1,19 @@
 use crate::utils::{dist, nearest_point_on_segment};
-use crate::{ModelerInput, ModelerParams, ModelerPartial};
+use crate::{IntegrationMethod, ModelerError, ModelerInput, ModelerParams, ModelerPartial};
 
 /// This struct models the movement of the pen tip based on the laws of motion.
 /// The pen tip is represented as a mass, connected by a spring to a moving
 /// anchor; as the anchor moves, it drags the pen tip along behind it.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct PositionModeler {
     //parameters for the model
     position_modeler_spring_mass_constant: f32,
     position_modeler_drag_constant: f32,
+    position_modeler_cfl_safety_factor: f32,
+    /// which numerical integration scheme [PositionModeler::update_single_step] dispatches to,
+    /// see [crate::ModelerParams::position_modeler_integration_method]
+    position_modeler_integration_method: IntegrationMethod,
     // last state
     pub(crate) state: ModelerPartial,
 }
@@ -17,17 +23,61 @@ impl PositionModeler {
         Self {
             position_modeler_spring_mass_constant: params.position_modeler_spring_mass_constant,
             position_modeler_drag_constant: params.position_modeler_drag_constant,
+            position_modeler_cfl_safety_factor: params.position_modeler_cfl_safety_factor,
+            position_modeler_integration_method: params.position_modeler_integration_method,
             state: ModelerPartial {
-                pos: first_input.pos,
+                pos: (first_input.pos.0 as f32, first_input.pos.1 as f32),
                 velocity: (0.0, 0.0),
                 acceleration: (0.0, 0.0),
                 time: first_input.time,
             },
         }
     }
+
+    /// Number of equal sub-steps a step of `delta_time` must be split into to stay within
+    /// the CFL-style stability bound for the explicit Euler integration: each sub-step is
+    /// kept below both the oscillation period `2*PI*sqrt(m)` and the drag relaxation time
+    /// `1/c`, scaled by [ModelerParams::position_modeler_cfl_safety_factor]. Returns 1 when
+    /// `delta_time` is already within the bound.
+    fn stability_sub_steps(&self, delta_time: f32) -> i32 {
+        let m = self.position_modeler_spring_mass_constant;
+        let c = self.position_modeler_drag_constant;
+        let oscillation_period = 2.0 * std::f32::consts::PI * m.sqrt();
+        let drag_relaxation_time = 1.0 / c;
+        let max_stable_dt =
+            self.position_modeler_cfl_safety_factor * oscillation_period.min(drag_relaxation_time);
+
+        if max_stable_dt <= 0.0 || delta_time <= max_stable_dt {
+            1
+        } else {
+            (delta_time / max_stable_dt).ceil() as i32
+        }
+    }
+
+    // Given the position of the anchor and the time, updates the model and returns the new
+    // state of the pen tip, one single sub-step, using
+    // [PositionModeler::position_modeler_integration_method]
+    fn update_single_step(&mut self, anchor_pos: (f32, f32), time: f64) -> ModelerPartial {
+        match self.position_modeler_integration_method {
+            IntegrationMethod::SemiImplicitEuler => {
+                self.update_single_step_semi_implicit_euler(anchor_pos, time)
+            }
+            IntegrationMethod::ForwardEuler => {
+                self.update_single_step_forward_euler(anchor_pos, time)
+            }
+            IntegrationMethod::Rk4 => self.update_single_step_rk4(anchor_pos, time),
+        }
+    }
+
     // Given the position of the anchor and the time, updates the model and
-    // returns the new state of the pen tip
-    pub(crate) fn update(&mut self, anchor_pos: (f32, f32), time: f64) -> ModelerPartial {
+    // returns the new state of the pen tip, one single semi-implicit (symplectic) Euler step:
+    // velocity is updated from the current acceleration first, then position is advanced
+    // using the *already-updated* velocity.
+    fn update_single_step_semi_implicit_euler(
+        &mut self,
+        anchor_pos: (f32, f32),
+        time: f64,
+    ) -> ModelerPartial {
         let delta_time = (time - self.state.time) as f32;
         //
         self.state.acceleration = (
@@ -49,6 +99,145 @@ impl PositionModeler {
         self.state.clone()
     }
 
+    // Plain explicit (forward) Euler: position is advanced using the velocity from *before*
+    // this sub-step, and velocity is updated from the acceleration at the start of the
+    // sub-step. Simpler, but less stable than the semi-implicit step at large step sizes.
+    fn update_single_step_forward_euler(
+        &mut self,
+        anchor_pos: (f32, f32),
+        time: f64,
+    ) -> ModelerPartial {
+        let delta_time = (time - self.state.time) as f32;
+        let old_velocity = self.state.velocity;
+        self.state.acceleration = self.acceleration_at(anchor_pos, self.state.pos, old_velocity);
+        self.state.pos = (
+            self.state.pos.0 + delta_time * old_velocity.0,
+            self.state.pos.1 + delta_time * old_velocity.1,
+        );
+        self.state.velocity = (
+            old_velocity.0 + delta_time * self.state.acceleration.0,
+            old_velocity.1 + delta_time * self.state.acceleration.1,
+        );
+        self.state.time = time;
+
+        self.state.clone()
+    }
+
+    // Fourth-order Runge-Kutta: evaluates the `(velocity, acceleration)` derivative four times
+    // per sub-step (at the start, twice at the midpoint, and at the end) and combines them
+    // with the classic `1/6, 1/3, 1/3, 1/6` weights.
+    fn update_single_step_rk4(&mut self, anchor_pos: (f32, f32), time: f64) -> ModelerPartial {
+        let h = (time - self.state.time) as f32;
+        let pos0 = self.state.pos;
+        let vel0 = self.state.velocity;
+
+        let k1_vel = vel0;
+        let k1_acc = self.acceleration_at(anchor_pos, pos0, vel0);
+
+        let pos_k2 = (pos0.0 + 0.5 * h * k1_vel.0, pos0.1 + 0.5 * h * k1_vel.1);
+        let k2_vel = (vel0.0 + 0.5 * h * k1_acc.0, vel0.1 + 0.5 * h * k1_acc.1);
+        let k2_acc = self.acceleration_at(anchor_pos, pos_k2, k2_vel);
+
+        let pos_k3 = (pos0.0 + 0.5 * h * k2_vel.0, pos0.1 + 0.5 * h * k2_vel.1);
+        let k3_vel = (vel0.0 + 0.5 * h * k2_acc.0, vel0.1 + 0.5 * h * k2_acc.1);
+        let k3_acc = self.acceleration_at(anchor_pos, pos_k3, k3_vel);
+
+        let pos_k4 = (pos0.0 + h * k3_vel.0, pos0.1 + h * k3_vel.1);
+        let k4_vel = (vel0.0 + h * k3_acc.0, vel0.1 + h * k3_acc.1);
+        let k4_acc = self.acceleration_at(anchor_pos, pos_k4, k4_vel);
+
+        self.state.acceleration = k1_acc;
+        self.state.pos = (
+            pos0.0 + (h / 6.0) * (k1_vel.0 + 2.0 * k2_vel.0 + 2.0 * k3_vel.0 + k4_vel.0),
+            pos0.1 + (h / 6.0) * (k1_vel.1 + 2.0 * k2_vel.1 + 2.0 * k3_vel.1 + k4_vel.1),
+        );
+        self.state.velocity = (
+            vel0.0 + (h / 6.0) * (k1_acc.0 + 2.0 * k2_acc.0 + 2.0 * k3_acc.0 + k4_acc.0),
+            vel0.1 + (h / 6.0) * (k1_acc.1 + 2.0 * k2_acc.1 + 2.0 * k3_acc.1 + k4_acc.1),
+        );
+        self.state.time = time;
+
+        self.state.clone()
+    }
+
+    /// Given the position of the anchor and the time, updates the model and returns the
+    /// new state of the pen tip. If `time` is far enough from the current state's time
+    /// that a single Euler step would be unstable (see [PositionModeler::stability_sub_steps]),
+    /// this internally subdivides the step into several equal sub-steps instead.
+    pub(crate) fn update(&mut self, anchor_pos: (f32, f32), time: f64) -> ModelerPartial {
+        let delta_time = (time - self.state.time) as f32;
+        let n_steps = self.stability_sub_steps(delta_time);
+        if n_steps <= 1 {
+            return self.update_single_step(anchor_pos, time);
+        }
+
+        let start_time = self.state.time;
+        let mut result = self.state.clone();
+        for i in 1..=n_steps {
+            let frac_adv = i as f64 / n_steps as f64;
+            result =
+                self.update_single_step(anchor_pos, start_time + frac_adv * (time - start_time));
+        }
+        result
+    }
+
+    /// Like [PositionModeler::update], but returns every intermediate sub-step instead of
+    /// only the final one: the step is split into `max(stability sub-steps, output
+    /// sub-steps)` pieces, where the output sub-step count is derived from
+    /// `min_output_rate` (the minimum number of outputs to produce per unit time), matching
+    /// the C++ `sampling_params.min_output_rate` behaviour but applied within a single step.
+    // alternate entry point exercised by tests only; no production call site resamples a
+    // single step yet
+    #[allow(dead_code)]
+    pub(crate) fn update_resampled(
+        &mut self,
+        anchor_pos: (f32, f32),
+        time: f64,
+        min_output_rate: f64,
+    ) -> Vec<ModelerPartial> {
+        let delta_time = time - self.state.time;
+        let stability_steps = self.stability_sub_steps(delta_time as f32);
+        let output_steps = if min_output_rate > 0.0 {
+            (delta_time * min_output_rate).ceil() as i32
+        } else {
+            1
+        };
+        let n_steps = stability_steps.max(output_steps).max(1);
+
+        let start_time = self.state.time;
+        (1..=n_steps)
+            .map(|i| {
+                let frac_adv = i as f64 / n_steps as f64;
+                self.update_single_step(anchor_pos, start_time + frac_adv * delta_time)
+            })
+            .collect()
+    }
+
+    /// Validating counterpart to [PositionModeler::update], for embedders feeding in
+    /// untrusted device streams: rejects a non-monotonic `time` and non-finite
+    /// `anchor_pos`/`time` instead of silently producing a zero/negative step and
+    /// NaN-propagating velocities. Delegates to [PositionModeler::update] once validated.
+    // alternate entry point exercised by tests only; production callers validate at the
+    // element-stream level ([ModelerError::Element]) before ever reaching [PositionModeler]
+    #[allow(dead_code)]
+    pub(crate) fn try_update(
+        &mut self,
+        anchor_pos: (f32, f32),
+        time: f64,
+    ) -> Result<ModelerPartial, ModelerError> {
+        if !anchor_pos.0.is_finite() || !anchor_pos.1.is_finite() || !time.is_finite() {
+            return Err(ModelerError::NonFiniteInput);
+        }
+        if time <= self.state.time {
+            return Err(ModelerError::NonMonotonicTime {
+                prev: self.state.time,
+                cur: time,
+            });
+        }
+
+        Ok(self.update(anchor_pos, time))
+    }
+
     /// update the model `n_steps` time between events
     /// this upsample between inputs linearly and applies
     /// these upstreamed events to the model
@@ -75,6 +264,216 @@ impl PositionModeler {
             .collect()
     }
 
+    /// Validating counterpart to [PositionModeler::update_along_linear_path]: rejects a
+    /// non-positive `n_steps`, an `end_time` that doesn't strictly follow `start_time`, and
+    /// non-finite positions or times, instead of upsampling a malformed or degenerate path.
+    // alternate entry point exercised by tests only; production callers validate at the
+    // element-stream level ([ModelerError::Element]) before ever reaching [PositionModeler]
+    #[allow(dead_code)]
+    pub(crate) fn try_update_along_linear_path(
+        &mut self,
+        start_pos: (f32, f32),
+        start_time: f64,
+        end_pos: (f32, f32),
+        end_time: f64,
+        n_steps: i32,
+    ) -> Result<Vec<ModelerPartial>, ModelerError> {
+        if !start_pos.0.is_finite()
+            || !start_pos.1.is_finite()
+            || !end_pos.0.is_finite()
+            || !end_pos.1.is_finite()
+            || !start_time.is_finite()
+            || !end_time.is_finite()
+        {
+            return Err(ModelerError::NonFiniteInput);
+        }
+        if n_steps <= 0 {
+            return Err(ModelerError::InvalidStepCount(n_steps));
+        }
+        if end_time <= start_time {
+            return Err(ModelerError::InvalidTimeRange {
+                start: start_time,
+                end: end_time,
+            });
+        }
+
+        Ok(self.update_along_linear_path(start_pos, start_time, end_pos, end_time, n_steps))
+    }
+
+    /// Exact (closed-form) integration of the spring-mass-damper ODE for a fixed anchor,
+    /// unconditionally stable regardless of `delta_time` or how stiff the spring is.
+    ///
+    /// With `m = position_modeler_spring_mass_constant`, `c = position_modeler_drag_constant`
+    /// and displacement `d = pos - anchor_pos`, the pen tip obeys `d'' + c·d' + d/m = 0` per
+    /// coordinate. This solves that linear ODE analytically over the step instead of taking a
+    /// single explicit Euler step, picking the underdamped, critically-damped or overdamped
+    /// branch based on the sign of the characteristic discriminant.
+    // alternate integration strategy exercised by tests only; not yet wired into
+    // [ModelerParams::position_modeler_integration_method]'s dispatch
+    #[allow(dead_code)]
+    pub(crate) fn update_exact(&mut self, anchor_pos: (f32, f32), time: f64) -> ModelerPartial {
+        let delta_time = (time - self.state.time) as f32;
+        let m = self.position_modeler_spring_mass_constant;
+        let c = self.position_modeler_drag_constant;
+
+        let (dx, vx) = exact_step(
+            self.state.pos.0 - anchor_pos.0,
+            self.state.velocity.0,
+            m,
+            c,
+            delta_time,
+        );
+        let (dy, vy) = exact_step(
+            self.state.pos.1 - anchor_pos.1,
+            self.state.velocity.1,
+            m,
+            c,
+            delta_time,
+        );
+
+        self.state.pos = (anchor_pos.0 + dx, anchor_pos.1 + dy);
+        self.state.velocity = (vx, vy);
+        // the analytic solution satisfies the ODE exactly at every instant, so the
+        // acceleration at the new state can be read straight off of it
+        self.state.acceleration = (
+            (anchor_pos.0 - self.state.pos.0) / m - c * vx,
+            (anchor_pos.1 - self.state.pos.1) / m - c * vy,
+        );
+        self.state.time = time;
+
+        self.state.clone()
+    }
+
+    /// [PositionModeler::update_along_linear_path], but integrating each sub-step with
+    /// [PositionModeler::update_exact] instead of the explicit Euler [PositionModeler::update]
+    // alternate integration strategy exercised by tests only, see [PositionModeler::update_exact]
+    #[allow(dead_code)]
+    pub(crate) fn update_along_linear_path_exact(
+        &mut self,
+        start_pos: (f32, f32),
+        start_time: f64,
+        end_pos: (f32, f32),
+        end_time: f64,
+        n_steps: i32,
+    ) -> Vec<ModelerPartial> {
+        (1..=n_steps)
+            .map(|i| {
+                let frac_adv: f32 = i as f32 / n_steps as f32;
+
+                let anchor_pos = (
+                    start_pos.0 + frac_adv * (end_pos.0 - start_pos.0),
+                    start_pos.1 + frac_adv * (end_pos.1 - start_pos.1),
+                );
+                let time = start_time + frac_adv as f64 * (end_time - start_time);
+
+                self.update_exact(anchor_pos, time)
+            })
+            .collect()
+    }
+
+    /// [PositionModeler::model_end_of_stroke], but integrating each candidate with
+    /// [PositionModeler::update_exact] instead of the explicit Euler [PositionModeler::update]
+    // alternate integration strategy exercised by tests only, see [PositionModeler::update_exact]
+    #[allow(dead_code)]
+    pub(crate) fn model_end_of_stroke_exact(
+        &mut self,
+        anchor_pos: (f32, f32),
+        delta_time: f64,
+        max_iterations: usize,
+        stop_distance: f32,
+    ) -> Vec<ModelerPartial> {
+        let initial_state = self.state.clone();
+        let mut delta_time = delta_time;
+
+        let mut out_events = Vec::<ModelerPartial>::with_capacity(max_iterations);
+        for _ in 0..max_iterations {
+            let previous_state = self.state.clone();
+            let candidate = self.update_exact(anchor_pos, previous_state.time + delta_time);
+
+            if dist(previous_state.pos, candidate.pos) < stop_distance {
+                self.state = initial_state;
+                return out_events;
+            }
+
+            if nearest_point_on_segment(
+                (previous_state.pos.0, previous_state.pos.1),
+                (candidate.pos.0, candidate.pos.1),
+                (anchor_pos.0, anchor_pos.1),
+            ) < 1.0
+            {
+                delta_time *= 0.5;
+                self.state = previous_state;
+                continue;
+            } else {
+                out_events.push(candidate.clone());
+            }
+
+            if dist(candidate.pos, anchor_pos) < stop_distance {
+                self.state = initial_state;
+                return out_events;
+            }
+        }
+        self.state = initial_state;
+        out_events
+    }
+
+    /// Non-mutating smoothing pass over an externally-produced sequence of anchor positions
+    /// (e.g. a [`KalmanPredictor`](crate::prediction::KalmanPredictor)'s projected tail),
+    /// driving the spring-mass-damper towards each anchor in turn so the predicted tail comes
+    /// out smoothed the same way the modeled stroke itself is, rather than handed to the
+    /// caller as raw extrapolator output. Saves and restores `state` exactly like
+    /// [PositionModeler::model_end_of_stroke].
+    pub(crate) fn predict_through(&mut self, anchors: &[ModelerPartial]) -> Vec<ModelerPartial> {
+        let initial_state = self.state.clone();
+        let out_events = anchors
+            .iter()
+            .map(|anchor| self.update(anchor.pos, anchor.time))
+            .collect();
+        self.state = initial_state;
+        out_events
+    }
+
+    /// Non-mutating forward extrapolation of the trailing pen trajectory, to compensate for
+    /// display latency. Drives the spring-mass model towards an anchor advanced along the
+    /// current velocity direction, shrinking that advance by `decay` on every step so the
+    /// predicted tip eases out instead of shooting off at high speed. Saves and restores
+    /// `state` exactly like [PositionModeler::model_end_of_stroke].
+    ///
+    /// Returns an empty vector if the pen is effectively stationary (speed below
+    /// `velocity_floor`), otherwise `n_steps` partials spaced `step_duration` apart.
+    // alternate extrapolation strategy exercised by tests only; [StrokeModeler::predict] goes
+    // through [PositionModeler::model_end_of_stroke] or, for Kalman prediction,
+    // [PositionModeler::predict_through] instead
+    #[allow(dead_code)]
+    pub(crate) fn predict(
+        &mut self,
+        n_steps: usize,
+        step_duration: f64,
+        velocity_floor: f32,
+        decay: f32,
+    ) -> Vec<ModelerPartial> {
+        if dist((0.0, 0.0), self.state.velocity) < velocity_floor {
+            return Vec::new();
+        }
+
+        let initial_state = self.state.clone();
+        let mut advance = (
+            self.state.velocity.0 * step_duration as f32,
+            self.state.velocity.1 * step_duration as f32,
+        );
+
+        let mut out_events = Vec::<ModelerPartial>::with_capacity(n_steps);
+        for _ in 0..n_steps {
+            let anchor_pos = (self.state.pos.0 + advance.0, self.state.pos.1 + advance.1);
+            let candidate = self.update(anchor_pos, self.state.time + step_duration);
+            out_events.push(candidate);
+            advance = (advance.0 * decay, advance.1 * decay);
+        }
+
+        self.state = initial_state;
+        out_events
+    }
+
     /// models the end of the stroke (catch-up) WITHOUT modifying the predictor
     /// (the state is saved then restored after calculations are done)
     ///
@@ -128,6 +527,194 @@ impl PositionModeler {
         self.state = initial_state;
         out_events
     }
+
+    /// Caps the recursion in [PositionModeler::update_predictor_corrector] so an
+    /// unreachable `error_tolerance` (e.g. `0.0`) halves `delta_time` down to
+    /// `min_delta_time` and stops, rather than looping forever.
+    #[allow(dead_code)]
+    const PREDICTOR_CORRECTOR_MAX_REFINEMENTS: u32 = 20;
+
+    /// Acceleration of the spring-mass-damper towards `anchor_pos` at the given `pos`/`vel`,
+    /// without touching `self.state` (used to re-evaluate the derivative at the predicted
+    /// state in [PositionModeler::update_predictor_corrector]).
+    fn acceleration_at(
+        &self,
+        anchor_pos: (f32, f32),
+        pos: (f32, f32),
+        vel: (f32, f32),
+    ) -> (f32, f32) {
+        (
+            (anchor_pos.0 - pos.0) / self.position_modeler_spring_mass_constant
+                - self.position_modeler_drag_constant * vel.0,
+            (anchor_pos.1 - pos.1) / self.position_modeler_spring_mass_constant
+                - self.position_modeler_drag_constant * vel.1,
+        )
+    }
+
+    /// Predictor-corrector (Heun / explicit trapezoidal) counterpart to
+    /// [PositionModeler::update_single_step]: takes an explicit-Euler predictor step, then a
+    /// corrector step using the average of the acceleration at the start and at the
+    /// predicted state, which damps the oscillation a plain Euler step exhibits when
+    /// `delta_time` is large relative to the spring-mass-damper's time constants.
+    ///
+    /// The predictor and corrector positions are compared; if they disagree by more than
+    /// `error_tolerance`, the step is rejected and retried as two half-steps, recursing
+    /// (bounded by [PositionModeler::PREDICTOR_CORRECTOR_MAX_REFINEMENTS]) until the
+    /// tolerance is met or `min_delta_time` is reached.
+    // alternate integration strategy exercised by tests only; not yet wired into
+    // [ModelerParams::position_modeler_integration_method]'s dispatch
+    #[allow(dead_code)]
+    pub(crate) fn update_predictor_corrector(
+        &mut self,
+        anchor_pos: (f32, f32),
+        time: f64,
+        error_tolerance: f32,
+        min_delta_time: f64,
+    ) -> ModelerPartial {
+        self.update_predictor_corrector_impl(
+            anchor_pos,
+            time,
+            error_tolerance,
+            min_delta_time,
+            Self::PREDICTOR_CORRECTOR_MAX_REFINEMENTS,
+        )
+    }
+
+    #[allow(dead_code)]
+    fn update_predictor_corrector_impl(
+        &mut self,
+        anchor_pos: (f32, f32),
+        time: f64,
+        error_tolerance: f32,
+        min_delta_time: f64,
+        refinements_left: u32,
+    ) -> ModelerPartial {
+        let delta_time = (time - self.state.time) as f32;
+        let pos0 = self.state.pos;
+        let vel0 = self.state.velocity;
+
+        let a0 = self.acceleration_at(anchor_pos, pos0, vel0);
+        let predictor_vel = (vel0.0 + delta_time * a0.0, vel0.1 + delta_time * a0.1);
+        let predictor_pos = (
+            pos0.0 + delta_time * predictor_vel.0,
+            pos0.1 + delta_time * predictor_vel.1,
+        );
+
+        let a1 = self.acceleration_at(anchor_pos, predictor_pos, predictor_vel);
+        let avg_accel = ((a0.0 + a1.0) * 0.5, (a0.1 + a1.1) * 0.5);
+        let corrector_vel = (
+            vel0.0 + delta_time * avg_accel.0,
+            vel0.1 + delta_time * avg_accel.1,
+        );
+        let corrector_pos = (
+            pos0.0 + delta_time * corrector_vel.0,
+            pos0.1 + delta_time * corrector_vel.1,
+        );
+
+        let step_error = dist(predictor_pos, corrector_pos);
+        let half_delta_time = (time - self.state.time) / 2.0;
+        if step_error > error_tolerance && half_delta_time >= min_delta_time && refinements_left > 0
+        {
+            let mid_time = self.state.time + half_delta_time;
+            self.update_predictor_corrector_impl(
+                anchor_pos,
+                mid_time,
+                error_tolerance,
+                min_delta_time,
+                refinements_left - 1,
+            );
+            return self.update_predictor_corrector_impl(
+                anchor_pos,
+                time,
+                error_tolerance,
+                min_delta_time,
+                refinements_left - 1,
+            );
+        }
+
+        self.state.pos = corrector_pos;
+        self.state.velocity = corrector_vel;
+        self.state.acceleration = a1;
+        self.state.time = time;
+        self.state.clone()
+    }
+
+    /// [PositionModeler::update_along_linear_path], but integrating each sub-step with
+    /// [PositionModeler::update_predictor_corrector] instead of the explicit Euler
+    /// [PositionModeler::update].
+    // alternate integration strategy exercised by tests only, see
+    // [PositionModeler::update_predictor_corrector]
+    #[allow(dead_code)]
+    pub(crate) fn update_along_linear_path_predictor_corrector(
+        &mut self,
+        start_pos: (f32, f32),
+        start_time: f64,
+        end_pos: (f32, f32),
+        end_time: f64,
+        n_steps: i32,
+        error_tolerance: f32,
+        min_delta_time: f64,
+    ) -> Vec<ModelerPartial> {
+        (1..=n_steps)
+            .map(|i| {
+                let frac_adv: f32 = i as f32 / n_steps as f32;
+
+                let anchor_pos = (
+                    start_pos.0 + frac_adv * (end_pos.0 - start_pos.0),
+                    start_pos.1 + frac_adv * (end_pos.1 - start_pos.1),
+                );
+                let time = start_time + frac_adv as f64 * (end_time - start_time);
+
+                self.update_predictor_corrector(anchor_pos, time, error_tolerance, min_delta_time)
+            })
+            .collect()
+    }
+}
+
+/// Solves `d'' + c·d' + d/m = 0` analytically over a step `h`, given the initial displacement
+/// `d0` and velocity `v0`, returning `(d(h), v(h))`.
+///
+/// Picks the underdamped, critically-damped or overdamped branch based on the sign of the
+/// characteristic discriminant `c²/4 - 1/m`, falling back to the critically-damped form
+/// whenever the discriminant is too close to zero to safely divide by it.
+#[allow(dead_code)]
+fn exact_step(d0: f32, v0: f32, m: f32, c: f32, h: f32) -> (f32, f32) {
+    let a = c / 2.0;
+    let discriminant = a * a - 1.0 / m;
+
+    if discriminant.abs() < 1e-6 {
+        // critically damped: repeated root s = -a
+        let s = -a;
+        let coeff_a = d0;
+        let coeff_b = v0 - s * d0;
+        let decay = (s * h).exp();
+        let d = (coeff_a + coeff_b * h) * decay;
+        let v = (coeff_b + s * (coeff_a + coeff_b * h)) * decay;
+        (d, v)
+    } else if discriminant < 0.0 {
+        // underdamped: complex conjugate roots, omega = sqrt(1/m - c^2/4)
+        let omega = (-discriminant).sqrt();
+        let coeff_a = d0;
+        let coeff_b = (v0 + a * d0) / omega;
+        let decay = (-a * h).exp();
+        let (sin_wh, cos_wh) = (omega * h).sin_cos();
+        let d = decay * (coeff_a * cos_wh + coeff_b * sin_wh);
+        let v = decay
+            * ((-a * coeff_a + omega * coeff_b) * cos_wh
+                + (-a * coeff_b - omega * coeff_a) * sin_wh);
+        (d, v)
+    } else {
+        // overdamped: two distinct real roots
+        let r = discriminant.sqrt();
+        let s1 = -a + r;
+        let s2 = -a - r;
+        let coeff_a = (v0 - s2 * d0) / (s1 - s2);
+        let coeff_b = d0 - coeff_a;
+        let (term_a, term_b) = (coeff_a * (s1 * h).exp(), coeff_b * (s2 * h).exp());
+        let d = term_a + term_b;
+        let v = s1 * term_a + s2 * term_b;
+        (d, v)
+    }
 }
 
 impl ModelerPartial {
@@ -355,7 +942,10 @@ fn smooth_turn() {
         ModelerParams::suggested(),
         ModelerInput {
             time: current_time,
-            pos: point_on_circle(0.0),
+            pos: {
+                let (x, y) = point_on_circle(0.0);
+                (x as f64, y as f64)
+            },
             ..ModelerInput::default()
         },
     );
@@ -599,6 +1189,10 @@ fn end_of_stroke_motion() {
         position_modeler_drag_constant: ModelerParams::suggested().position_modeler_drag_constant,
         position_modeler_spring_mass_constant: ModelerParams::suggested()
             .position_modeler_spring_mass_constant,
+        position_modeler_cfl_safety_factor: ModelerParams::suggested()
+            .position_modeler_cfl_safety_factor,
+        position_modeler_integration_method: ModelerParams::suggested()
+            .position_modeler_integration_method,
         state: ModelerPartial {
             pos: (-1.0, 2.0),
             velocity: (40.0, 10.0),
@@ -670,6 +1264,10 @@ fn end_of_stroke_maxiters() {
         position_modeler_drag_constant: ModelerParams::suggested().position_modeler_drag_constant,
         position_modeler_spring_mass_constant: ModelerParams::suggested()
             .position_modeler_spring_mass_constant,
+        position_modeler_cfl_safety_factor: ModelerParams::suggested()
+            .position_modeler_cfl_safety_factor,
+        position_modeler_integration_method: ModelerParams::suggested()
+            .position_modeler_integration_method,
         state: ModelerPartial {
             pos: (8.0, -3.0),
             velocity: (-100.0, -150.0),
@@ -746,3 +1344,354 @@ fn end_of_stroke_maxiters() {
         .zip(expected)
         .fold(true, |acc, x| { acc && x.0.near(x.1) }));
 }
+
+#[test]
+fn update_exact_matches_euler_for_small_steps() {
+    // for a small enough step the explicit Euler step and the exact analytic solution
+    // should agree closely, since Euler converges to the exact ODE solution as h -> 0
+    let params = ModelerParams::suggested();
+    let mut euler = PositionModeler::new(params, ModelerInput::default());
+    let mut exact = PositionModeler::new(params, ModelerInput::default());
+
+    let tiny_step = 1e-6;
+    let euler_result = euler.update((1.0, 0.5), tiny_step);
+    let exact_result = exact.update_exact((1.0, 0.5), tiny_step);
+
+    let tol = 1e-3;
+    approx::assert_abs_diff_eq!(euler_result.pos.0, exact_result.pos.0, epsilon = tol);
+    approx::assert_abs_diff_eq!(euler_result.pos.1, exact_result.pos.1, epsilon = tol);
+    approx::assert_abs_diff_eq!(
+        euler_result.velocity.0,
+        exact_result.velocity.0,
+        epsilon = tol
+    );
+    approx::assert_abs_diff_eq!(
+        euler_result.velocity.1,
+        exact_result.velocity.1,
+        epsilon = tol
+    );
+}
+
+#[test]
+fn update_exact_remains_stable_for_large_steps() {
+    // a step large enough that the explicit Euler integration would blow up should still
+    // produce a finite, bounded result under the exact analytic integration
+    let mut modeler = PositionModeler::new(ModelerParams::suggested(), ModelerInput::default());
+
+    let result = modeler.update_exact((1.0, -1.0), 10.0);
+    assert!(result.pos.0.is_finite() && result.pos.1.is_finite());
+    assert!(result.velocity.0.is_finite() && result.velocity.1.is_finite());
+    // the pen tip should have settled near the anchor, not overshot to infinity
+    assert!(dist(result.pos, (1.0, -1.0)) < 0.01);
+}
+
+#[test]
+fn exact_step_critically_and_overdamped_branches() {
+    // critically damped: c^2/4 == 1/m exactly
+    let (d, v) = exact_step(1.0, 0.0, 1.0, 2.0, 1.0);
+    assert!(d.is_finite() && v.is_finite());
+
+    // overdamped: c^2/4 > 1/m
+    let (d, v) = exact_step(1.0, 0.0, 1.0, 10.0, 1.0);
+    assert!(d.is_finite() && v.is_finite());
+    // strongly overdamped motion should decay towards the anchor without oscillating
+    assert!(d.abs() <= 1.0);
+}
+
+#[test]
+fn update_predictor_corrector_matches_single_step_for_small_steps() {
+    // for a small enough step the predictor-corrector and the plain single Euler step
+    // should agree closely, since both converge to the same ODE solution as h -> 0
+    let params = ModelerParams::suggested();
+    let mut euler = PositionModeler::new(params, ModelerInput::default());
+    let mut predictor_corrector = PositionModeler::new(params, ModelerInput::default());
+
+    let tiny_step = 1e-6;
+    let euler_result = euler.update((1.0, 0.5), tiny_step);
+    let pc_result =
+        predictor_corrector.update_predictor_corrector((1.0, 0.5), tiny_step, 1e-3, 1e-9);
+
+    let tol = 1e-3;
+    approx::assert_abs_diff_eq!(euler_result.pos.0, pc_result.pos.0, epsilon = tol);
+    approx::assert_abs_diff_eq!(euler_result.pos.1, pc_result.pos.1, epsilon = tol);
+}
+
+#[test]
+fn update_predictor_corrector_remains_stable_for_large_steps() {
+    // a step large enough that a plain explicit Euler step would overshoot and oscillate
+    // should still settle near the anchor once the adaptive corrector is allowed to halve
+    // the step down to a tight error tolerance
+    let mut modeler = PositionModeler::new(ModelerParams::suggested(), ModelerInput::default());
+
+    let result = modeler.update_predictor_corrector((1.0, -1.0), 10.0, 1e-4, 1e-6);
+    assert!(result.pos.0.is_finite() && result.pos.1.is_finite());
+    assert!(result.velocity.0.is_finite() && result.velocity.1.is_finite());
+    assert!(dist(result.pos, (1.0, -1.0)) < 0.01);
+}
+
+#[test]
+fn update_predictor_corrector_halves_steps_until_min_delta_time_when_tolerance_is_unreachable() {
+    // an error_tolerance of 0.0 can never be satisfied exactly, so the recursion should
+    // keep halving delta_time down to min_delta_time (and stop there, bounded by
+    // PREDICTOR_CORRECTOR_MAX_REFINEMENTS) rather than looping forever
+    let mut modeler = PositionModeler::new(ModelerParams::suggested(), ModelerInput::default());
+
+    let result = modeler.update_predictor_corrector((1.0, 0.0), 1.0, 0.0, 1e-3);
+    assert!(result.pos.0.is_finite() && result.pos.1.is_finite());
+    assert!(result.velocity.0.is_finite() && result.velocity.1.is_finite());
+    assert_eq!(result.time, 1.0);
+}
+
+#[test]
+fn update_along_linear_path_predictor_corrector_reaches_the_end_pos_and_time() {
+    let mut modeler = PositionModeler::new(ModelerParams::suggested(), ModelerInput::default());
+
+    let result = modeler.update_along_linear_path_predictor_corrector(
+        (0.0, 0.0),
+        0.0,
+        (1.0, 1.0),
+        1.0,
+        4,
+        1e-3,
+        1e-6,
+    );
+    assert_eq!(result.len(), 4);
+    assert!(result.windows(2).all(|pair| pair[1].time > pair[0].time));
+    approx::assert_abs_diff_eq!(result.last().unwrap().time, 1.0, epsilon = 1e-9);
+}
+
+#[test]
+fn forward_euler_and_rk4_agree_with_semi_implicit_euler_for_tiny_steps() {
+    // all three integration schemes should agree closely for a small enough step, since
+    // they all converge to the same ODE solution as h -> 0
+    let semi_implicit_params = ModelerParams::suggested();
+    let forward_euler_params = ModelerParams {
+        position_modeler_integration_method: IntegrationMethod::ForwardEuler,
+        ..ModelerParams::suggested()
+    };
+    let rk4_params = ModelerParams {
+        position_modeler_integration_method: IntegrationMethod::Rk4,
+        ..ModelerParams::suggested()
+    };
+
+    let mut semi_implicit = PositionModeler::new(semi_implicit_params, ModelerInput::default());
+    let mut forward_euler = PositionModeler::new(forward_euler_params, ModelerInput::default());
+    let mut rk4 = PositionModeler::new(rk4_params, ModelerInput::default());
+
+    let tiny_step = 1e-6;
+    let semi_implicit_result = semi_implicit.update((1.0, 0.5), tiny_step);
+    let forward_euler_result = forward_euler.update((1.0, 0.5), tiny_step);
+    let rk4_result = rk4.update((1.0, 0.5), tiny_step);
+
+    let tol = 1e-3;
+    approx::assert_abs_diff_eq!(
+        semi_implicit_result.pos.0,
+        forward_euler_result.pos.0,
+        epsilon = tol
+    );
+    approx::assert_abs_diff_eq!(semi_implicit_result.pos.0, rk4_result.pos.0, epsilon = tol);
+}
+
+#[test]
+fn rk4_remains_closer_to_the_anchor_than_forward_euler_for_a_single_large_sub_step() {
+    // `update()` normally subdivides a large delta_time into CFL-stable sub-steps regardless
+    // of integration method, so to actually exercise a single large sub-step (0.04, above
+    // forward Euler's stability limit of about 0.024 for these constants, where it overshoots
+    // and oscillates, but within RK4's) this calls the single-step methods directly, bypassing
+    // that subdivision, same as update_exact_remains_stable_for_large_steps does for the
+    // analytic integrator
+    let mut forward_euler =
+        PositionModeler::new(ModelerParams::suggested(), ModelerInput::default());
+    let mut rk4 = PositionModeler::new(ModelerParams::suggested(), ModelerInput::default());
+
+    let forward_euler_result = forward_euler.update_single_step_forward_euler((1.0, -1.0), 0.04);
+    let rk4_result = rk4.update_single_step_rk4((1.0, -1.0), 0.04);
+
+    assert!(forward_euler_result.pos.0.is_finite() && forward_euler_result.pos.1.is_finite());
+    assert!(rk4_result.pos.0.is_finite() && rk4_result.pos.1.is_finite());
+    // RK4 should end up closer to the anchor than plain forward Euler for this step size
+    assert!(dist(rk4_result.pos, (1.0, -1.0)) < dist(forward_euler_result.pos, (1.0, -1.0)));
+}
+
+#[test]
+fn try_update_rejects_non_monotonic_time() {
+    let mut modeler = PositionModeler::new(
+        ModelerParams::suggested(),
+        ModelerInput {
+            time: 1.0,
+            ..ModelerInput::default()
+        },
+    );
+
+    assert!(matches!(
+        modeler.try_update((1.0, 0.0), 1.0),
+        Err(ModelerError::NonMonotonicTime { prev, cur }) if prev == 1.0 && cur == 1.0
+    ));
+    assert!(matches!(
+        modeler.try_update((1.0, 0.0), 0.5),
+        Err(ModelerError::NonMonotonicTime { .. })
+    ));
+}
+
+#[test]
+fn try_update_rejects_non_finite_input() {
+    let mut modeler = PositionModeler::new(ModelerParams::suggested(), ModelerInput::default());
+
+    assert!(matches!(
+        modeler.try_update((f32::NAN, 0.0), 1.0),
+        Err(ModelerError::NonFiniteInput)
+    ));
+    assert!(matches!(
+        modeler.try_update((1.0, 0.0), f64::INFINITY),
+        Err(ModelerError::NonFiniteInput)
+    ));
+}
+
+#[test]
+fn try_update_accepts_valid_input() {
+    let mut modeler = PositionModeler::new(ModelerParams::suggested(), ModelerInput::default());
+    assert!(modeler.try_update((1.0, 0.0), 1. / 180.).is_ok());
+}
+
+#[test]
+fn try_update_along_linear_path_rejects_bad_step_count_and_time_range() {
+    let mut modeler = PositionModeler::new(
+        ModelerParams::suggested(),
+        ModelerInput {
+            time: 3.0,
+            pos: (5.0, 10.0),
+            ..ModelerInput::default()
+        },
+    );
+
+    assert!(matches!(
+        modeler.try_update_along_linear_path((5.0, 10.0), 3.0, (15., 10.), 3.05, 0),
+        Err(ModelerError::InvalidStepCount(0))
+    ));
+    assert!(matches!(
+        modeler.try_update_along_linear_path((5.0, 10.0), 3.0, (15., 10.), 2.0, 5),
+        Err(ModelerError::InvalidTimeRange { start, end }) if start == 3.0 && end == 2.0
+    ));
+    assert!(matches!(
+        modeler.try_update_along_linear_path((5.0, 10.0), 3.0, (f32::NAN, 10.), 3.05, 5),
+        Err(ModelerError::NonFiniteInput)
+    ));
+}
+
+#[test]
+fn update_subdivides_large_steps_for_stability() {
+    // a safety factor of 1.0 (the suggested default) gives a max stable step of
+    // 1 / position_modeler_drag_constant = 1/72 =~ 0.0139 here; a step an order of
+    // magnitude larger than that should still produce a finite, bounded result
+    let mut modeler = PositionModeler::new(ModelerParams::suggested(), ModelerInput::default());
+
+    let result = modeler.update((1.0, -1.0), 1.0);
+    assert!(result.pos.0.is_finite() && result.pos.1.is_finite());
+    assert!(result.velocity.0.is_finite() && result.velocity.1.is_finite());
+}
+
+#[test]
+fn update_resampled_returns_one_partial_per_sub_step() {
+    let mut modeler = PositionModeler::new(ModelerParams::suggested(), ModelerInput::default());
+
+    // a tiny step, well within the stability bound and below any output-rate floor,
+    // should collapse to a single partial, same as update()
+    let single = modeler.update_resampled((1.0, 0.0), 1. / 180., 0.0);
+    assert_eq!(single.len(), 1);
+
+    // asking for a high min_output_rate over a larger step should yield several
+    // intermediate partials, each one further along in time than the last
+    let mut modeler = PositionModeler::new(ModelerParams::suggested(), ModelerInput::default());
+    let resampled = modeler.update_resampled((1.0, 0.0), 0.1, 180.0);
+    assert!(resampled.len() >= 18);
+    assert!(resampled.windows(2).all(|pair| pair[1].time > pair[0].time));
+    assert!((resampled.last().unwrap().time - 0.1).abs() < 1e-9);
+}
+
+#[test]
+fn predict_returns_empty_when_stationary() {
+    let mut modeler = PositionModeler::new(ModelerParams::suggested(), ModelerInput::default());
+    assert!(modeler.predict(5, 1. / 180., 1.0, 0.9).is_empty());
+}
+
+#[test]
+fn predict_extrapolates_without_mutating_state() {
+    let mut modeler = PositionModeler {
+        position_modeler_drag_constant: ModelerParams::suggested().position_modeler_drag_constant,
+        position_modeler_spring_mass_constant: ModelerParams::suggested()
+            .position_modeler_spring_mass_constant,
+        position_modeler_cfl_safety_factor: ModelerParams::suggested()
+            .position_modeler_cfl_safety_factor,
+        position_modeler_integration_method: ModelerParams::suggested()
+            .position_modeler_integration_method,
+        state: ModelerPartial {
+            pos: (0.0, 0.0),
+            velocity: (40.0, 0.0),
+            acceleration: (0.0, 0.0),
+            time: 1.0,
+        },
+    };
+    let state_before = modeler.state.clone();
+
+    let prediction = modeler.predict(5, 1. / 180., 1.0, 0.9);
+    assert_eq!(prediction.len(), 5);
+    // state is restored exactly, like model_end_of_stroke
+    assert!(modeler.state.clone().near(state_before));
+    // the tip should keep moving roughly along the initial velocity direction
+    assert!(prediction.last().unwrap().pos.0 > 0.0);
+    assert!(prediction
+        .windows(2)
+        .all(|pair| pair[1].time > pair[0].time));
+}
+
+#[test]
+fn predict_eases_out_as_decay_shrinks_the_advance() {
+    // a decay factor very close to 0 collapses the advance after the first step, so the
+    // predicted tip should settle near its position rather than keep accelerating away
+    let mut modeler = PositionModeler::new(
+        ModelerParams::suggested(),
+        ModelerInput {
+            pos: (0.0, 0.0),
+            ..ModelerInput::default()
+        },
+    );
+    modeler.state.velocity = (40.0, 0.0);
+
+    let prediction = modeler.predict(10, 1. / 180., 1.0, 0.01);
+    let settled = prediction.last().unwrap().pos.0;
+    assert!(prediction
+        .iter()
+        .all(|partial| (partial.pos.0 - settled).abs() < 1.0));
+}
+
+#[test]
+fn predict_through_smooths_anchors_without_mutating_state() {
+    let mut modeler = PositionModeler::new(ModelerParams::suggested(), ModelerInput::default());
+    let state_before = modeler.state.clone();
+
+    let anchors = vec![
+        ModelerPartial {
+            pos: (1.0, 0.0),
+            velocity: (0.0, 0.0),
+            acceleration: (0.0, 0.0),
+            time: 1. / 180.,
+        },
+        ModelerPartial {
+            pos: (2.0, 0.0),
+            velocity: (0.0, 0.0),
+            acceleration: (0.0, 0.0),
+            time: 2. / 180.,
+        },
+    ];
+    let smoothed = modeler.predict_through(&anchors);
+
+    assert_eq!(smoothed.len(), anchors.len());
+    // the smoothed tip trails the raw anchors (the spring has not yet caught up), rather
+    // than reproducing them verbatim
+    assert!(smoothed[1].pos.0 > 0.0 && smoothed[1].pos.0 < anchors[1].pos.0);
+    // the timestamps are carried through unchanged
+    assert_eq!(smoothed[0].time, anchors[0].time);
+    assert_eq!(smoothed[1].time, anchors[1].time);
+    // state is restored exactly, like model_end_of_stroke
+    assert!(modeler.state.clone().near(state_before));
+}
@@ -0,0 +1,530 @@
+// Kalman-filter based forward prediction of the trailing stroke motion.
+//
+// The goal is to extrapolate a short tail of future [ModelerPartial] states so that
+// rendering can compensate for display latency. This is independent from the
+// spring-mass-damper position modeler: it tracks each axis (x, y) with its own linear
+// Kalman filter over the state vector [position, velocity, acceleration, jerk], using
+// the standard constant-jerk transition matrix over the inter-sample `delta_t`.
+
+use crate::utils::{dist, interp2, normalize01_32};
+use crate::ModelerPartial;
+
+/// Tuning parameters for the [`KalmanPredictor`].
+///
+/// Mirrors [`ModelerParams`](crate::ModelerParams)'s pattern of a `suggested()` constructor
+/// plus a `validate()` that collects error strings.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KalmanPredictorParams {
+    /// Process noise scalar applied to the covariance prediction step.
+    ///
+    /// Should be positive.
+    pub process_noise: f32,
+    /// Measurement noise scalar applied to the scalar position correction step.
+    ///
+    /// Should be positive.
+    pub measurement_noise: f32,
+    /// Number of updates the filter must have seen before its estimate is
+    /// considered stable enough to predict from.
+    pub min_stable_iteration: usize,
+    /// The number of points the predicted tail grows towards once the filter is stable.
+    pub desired_number_of_samples: usize,
+    /// Time step, in the same units as [`ModelerInput::time`](crate::ModelerInput::time), used
+    /// to forward-integrate the predicted tail.
+    ///
+    /// Should be positive.
+    pub prediction_interval: f64,
+    /// Upper bound on the cumulative extrapolated travel distance of the predicted tail.
+    ///
+    /// Should be positive.
+    pub max_estimation_distance: f32,
+    /// Below this speed, no prediction is produced (the pen is considered to be at rest).
+    pub min_travel_speed: f32,
+    /// Speed above which the extrapolation is clamped, to avoid overshooting on outliers.
+    ///
+    /// Should be greater than `min_travel_speed`.
+    pub max_travel_speed: f32,
+    /// Damping weight (< 1) applied to the acceleration term at every forward-integration step.
+    pub acceleration_weight: f32,
+    /// Damping weight (< 1) applied to the jerk term at every forward-integration step.
+    pub jerk_weight: f32,
+    /// How strongly the tail is pulled towards straight-line motion when the estimated
+    /// linearity of the recent trajectory is low.
+    pub baseline_linearity_confidence: f32,
+    /// Caps the filter's confidence relative to how far the pen has actually been moving:
+    /// if the tracking error exceeds this fraction of the recent raw-input travel distance,
+    /// the filter is treated as unstable and [`KalmanPredictor::predict`] returns an empty
+    /// tail instead of extrapolating from a comparatively large error.
+    ///
+    /// Should be positive.
+    pub max_error_to_travel_ratio: f32,
+}
+
+impl KalmanPredictorParams {
+    /// A reasonable starting point, tuned for stylus input sampled at display refresh rate.
+    pub fn suggested() -> Self {
+        Self {
+            process_noise: 0.01,
+            measurement_noise: 0.02,
+            min_stable_iteration: 4,
+            desired_number_of_samples: 8,
+            prediction_interval: 1. / 180.,
+            max_estimation_distance: 32.0,
+            min_travel_speed: 0.1,
+            max_travel_speed: 500.0,
+            acceleration_weight: 0.8,
+            jerk_weight: 0.4,
+            baseline_linearity_confidence: 0.6,
+            max_error_to_travel_ratio: 0.5,
+        }
+    }
+
+    /// validate the parameters as being correct, returns an error string with
+    /// the reasons otherwise
+    pub fn validate(self) -> Result<Self, String> {
+        let parameter_tests = [
+            self.process_noise > 0.0,
+            self.measurement_noise > 0.0,
+            self.prediction_interval > 0.0,
+            self.max_estimation_distance > 0.0,
+            self.min_travel_speed >= 0.0,
+            self.max_travel_speed > self.min_travel_speed,
+            self.acceleration_weight < 1.0,
+            self.jerk_weight < 1.0,
+            self.max_error_to_travel_ratio > 0.0,
+        ];
+
+        let errors = [
+            "`process_noise` is not positive; ",
+            "`measurement_noise` is not positive; ",
+            "`prediction_interval` is not positive; ",
+            "`max_estimation_distance` is not positive; ",
+            "`min_travel_speed` is negative; ",
+            "`max_travel_speed` should be strictly greater than `min_travel_speed`; ",
+            "`acceleration_weight` should be strictly smaller than 1.0; ",
+            "`jerk_weight` should be strictly smaller than 1.0; ",
+            "`max_error_to_travel_ratio` is not positive",
+        ];
+
+        let tests_passed = parameter_tests.iter().fold(true, |acc, x| acc & x);
+
+        if tests_passed {
+            Ok(self)
+        } else {
+            let error_acc = parameter_tests
+                .iter()
+                .zip(errors)
+                .filter(|x| !*(x.0))
+                .fold(String::from("the following errors occured : "), |acc, x| {
+                    acc + x.1
+                });
+            Err(error_acc)
+        }
+    }
+}
+
+/// A linear Kalman filter tracking `[position, velocity, acceleration, jerk]` for a single axis,
+/// using the standard constant-jerk transition model and a scalar position measurement.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct AxisFilter {
+    state: [f64; 4],
+    covariance: [[f64; 4]; 4],
+}
+
+impl AxisFilter {
+    fn new(initial_position: f32) -> Self {
+        let mut covariance = [[0.0; 4]; 4];
+        // a large initial variance: the starting velocity, acceleration and jerk are unknown,
+        // and a unit variance keeps the first corrections from moving them off zero
+        for (i, row) in covariance.iter_mut().enumerate() {
+            row[i] = 1e6;
+        }
+        Self {
+            state: [initial_position as f64, 0.0, 0.0, 0.0],
+            covariance,
+        }
+    }
+
+    /// Advances the filter by `dt`, then corrects it with a new scalar position `measurement`.
+    fn predict_and_correct(
+        &mut self,
+        measurement: f32,
+        dt: f64,
+        process_noise: f32,
+        measurement_noise: f32,
+    ) {
+        let f = transition_matrix(dt);
+
+        // predict: x = F x, P = F P F^T + Q
+        self.state = mat_vec_mul(&f, &self.state);
+        let ft = transpose(&f);
+        self.covariance = mat_mul(&mat_mul(&f, &self.covariance), &ft);
+        for (i, row) in self.covariance.iter_mut().enumerate() {
+            row[i] += process_noise as f64 * dt.max(0.0);
+        }
+
+        // correct against the scalar position measurement (H = [1, 0, 0, 0])
+        let innovation = measurement as f64 - self.state[0];
+        let innovation_covariance = self.covariance[0][0] + measurement_noise as f64;
+        if innovation_covariance.abs() < 1e-12 {
+            return;
+        }
+        let gain = [
+            self.covariance[0][0] / innovation_covariance,
+            self.covariance[1][0] / innovation_covariance,
+            self.covariance[2][0] / innovation_covariance,
+            self.covariance[3][0] / innovation_covariance,
+        ];
+        for i in 0..4 {
+            self.state[i] += gain[i] * innovation;
+        }
+        let mut new_covariance = self.covariance;
+        for i in 0..4 {
+            for j in 0..4 {
+                new_covariance[i][j] -= gain[i] * self.covariance[0][j];
+            }
+        }
+        self.covariance = new_covariance;
+    }
+}
+
+fn transition_matrix(dt: f64) -> [[f64; 4]; 4] {
+    [
+        [1.0, dt, dt * dt / 2.0, dt * dt * dt / 6.0],
+        [0.0, 1.0, dt, dt * dt / 2.0],
+        [0.0, 0.0, 1.0, dt],
+        [0.0, 0.0, 0.0, 1.0],
+    ]
+}
+
+fn transpose(m: &[[f64; 4]; 4]) -> [[f64; 4]; 4] {
+    let mut out = [[0.0; 4]; 4];
+    for i in 0..4 {
+        for j in 0..4 {
+            out[j][i] = m[i][j];
+        }
+    }
+    out
+}
+
+fn mat_mul(a: &[[f64; 4]; 4], b: &[[f64; 4]; 4]) -> [[f64; 4]; 4] {
+    let mut out = [[0.0; 4]; 4];
+    for i in 0..4 {
+        for j in 0..4 {
+            out[i][j] = (0..4).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    out
+}
+
+fn mat_vec_mul(a: &[[f64; 4]; 4], v: &[f64; 4]) -> [f64; 4] {
+    let mut out = [0.0; 4];
+    for i in 0..4 {
+        out[i] = (0..4).map(|k| a[i][k] * v[k]).sum();
+    }
+    out
+}
+
+/// Maintains the pair of per-axis [`AxisFilter`]s used to extrapolate the trailing stroke motion.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct KalmanPredictor {
+    params: KalmanPredictorParams,
+    x_filter: AxisFilter,
+    y_filter: AxisFilter,
+    iterations: usize,
+    last_raw_pos: (f32, f32),
+    last_time: f64,
+    /// exponentially-weighted estimate of the raw-input travel distance per update, used to
+    /// scale [`KalmanPredictorParams::max_error_to_travel_ratio`]
+    recent_travel: f32,
+}
+
+impl KalmanPredictor {
+    pub(crate) fn new(
+        params: KalmanPredictorParams,
+        first_pos: (f32, f32),
+        first_time: f64,
+    ) -> Self {
+        Self {
+            params,
+            x_filter: AxisFilter::new(first_pos.0),
+            y_filter: AxisFilter::new(first_pos.1),
+            iterations: 0,
+            last_raw_pos: first_pos,
+            last_time: first_time,
+            recent_travel: 0.0,
+        }
+    }
+
+    /// Run a predict+correct step against a new raw input.
+    pub(crate) fn update(&mut self, pos: (f32, f32), time: f64) {
+        // guard against delta_t == 0 by reusing the prior velocity estimate: passing dt=0
+        // leaves the transition matrix as identity, so the filter simply re-corrects in place.
+        let dt = (time - self.last_time).max(0.0);
+        self.x_filter.predict_and_correct(
+            pos.0,
+            dt,
+            self.params.process_noise,
+            self.params.measurement_noise,
+        );
+        self.y_filter.predict_and_correct(
+            pos.1,
+            dt,
+            self.params.process_noise,
+            self.params.measurement_noise,
+        );
+        self.recent_travel += 0.2 * (dist(self.last_raw_pos, pos) - self.recent_travel);
+        self.last_raw_pos = pos;
+        self.last_time = time;
+        self.iterations += 1;
+    }
+
+    /// Forward-integrates the tracked kinematic state, returning the extrapolated tail.
+    ///
+    /// Before the filter is stable (fewer than `min_stable_iteration` updates seen), this
+    /// returns an empty tail so the caller can fall back to the last modeled state.
+    pub(crate) fn predict(&self) -> Vec<ModelerPartial> {
+        if self.iterations < self.params.min_stable_iteration {
+            return Vec::new();
+        }
+
+        let estimated_pos = (self.x_filter.state[0] as f32, self.y_filter.state[0] as f32);
+        let tracking_error = dist(self.last_raw_pos, estimated_pos);
+        if tracking_error > self.recent_travel * self.params.max_error_to_travel_ratio {
+            return Vec::new();
+        }
+        let velocity = (self.x_filter.state[1] as f32, self.y_filter.state[1] as f32);
+        let speed = dist((0.0, 0.0), velocity);
+        if speed < self.params.min_travel_speed {
+            return Vec::new();
+        }
+
+        // four independent confidence factors, multiplied together into a number of
+        // samples between 0 and `desired_number_of_samples`: how far past the minimum
+        // stable sample count the filter has matured, how much the filter's tracking error
+        // has eaten into its error budget, how fast the pen is moving relative to the
+        // configured speed range, and how linear (vs. curving) the recent trajectory is
+        // estimated to be.
+        //
+        // the `min_stable_iteration` check above is a hard gate (no prediction at all before
+        // it), but having seen more samples past that floor keeps raising confidence rather
+        // than jumping straight to full trust on the very first stable iteration, so this
+        // ramps from 0 one update below the floor (already non-zero at the floor itself) up to
+        // 1 once the filter has seen twice that many.
+        let maturity_confidence = normalize01_32(
+            self.params.min_stable_iteration as f32 - 1.0,
+            2.0 * self.params.min_stable_iteration as f32,
+            self.iterations as f32,
+        );
+        let distance_confidence =
+            1.0 - (tracking_error / self.params.max_estimation_distance).clamp(0.0, 1.0);
+        let speed_confidence = normalize01_32(
+            self.params.min_travel_speed,
+            self.params.max_travel_speed,
+            speed,
+        );
+        // estimate how linear the recent trajectory is: a large jerk relative to the
+        // velocity indicates a curving stroke, a near-zero jerk a straight one.
+        let jerk_mag = dist(
+            (0.0, 0.0),
+            (self.x_filter.state[3] as f32, self.y_filter.state[3] as f32),
+        );
+        let linearity = 1.0 - (jerk_mag / (speed.max(1e-6) * 50.0)).clamp(0.0, 1.0);
+
+        let confidence = maturity_confidence * distance_confidence * speed_confidence * linearity;
+        let n_samples = ((confidence * self.params.desired_number_of_samples as f32).round()
+            as usize)
+            .min(self.params.desired_number_of_samples);
+        if n_samples == 0 {
+            return Vec::new();
+        }
+        let speed_scale = speed.min(self.params.max_travel_speed) / speed.max(1e-6);
+        let straight_blend = self.params.baseline_linearity_confidence * (1.0 - linearity);
+
+        let mut x_state = self.x_filter.state;
+        let mut y_state = self.y_filter.state;
+        x_state[1] *= speed_scale as f64;
+        y_state[1] *= speed_scale as f64;
+
+        let mut out = Vec::with_capacity(n_samples);
+        let mut prev_pos = estimated_pos;
+        let mut travelled = 0.0f32;
+        let mut time = self.last_time;
+
+        for _ in 0..n_samples {
+            x_state[2] *= self.params.acceleration_weight as f64;
+            x_state[3] *= self.params.jerk_weight as f64;
+            y_state[2] *= self.params.acceleration_weight as f64;
+            y_state[3] *= self.params.jerk_weight as f64;
+
+            let f = transition_matrix(self.params.prediction_interval);
+            x_state = mat_vec_mul(&f, &x_state);
+            y_state = mat_vec_mul(&f, &y_state);
+            time += self.params.prediction_interval;
+
+            let candidate_pos = (x_state[0] as f32, y_state[0] as f32);
+            let linear_pos = (
+                estimated_pos.0 + velocity.0 * (time - self.last_time) as f32,
+                estimated_pos.1 + velocity.1 * (time - self.last_time) as f32,
+            );
+            let blended_pos = interp2(candidate_pos, linear_pos, straight_blend);
+
+            let step_dist = dist(prev_pos, blended_pos);
+            travelled += step_dist;
+            if travelled > self.params.max_estimation_distance {
+                break;
+            }
+            // the acceleration/jerk decay above can slow the tail down well before
+            // `n_samples` steps are reached; stop as soon as it does, rather than
+            // continuing to emit near-stationary trailing points
+            let step_speed = dist((0.0, 0.0), (x_state[1] as f32, y_state[1] as f32));
+            if step_speed < self.params.min_travel_speed {
+                break;
+            }
+
+            out.push(ModelerPartial {
+                pos: blended_pos,
+                velocity: (x_state[1] as f32, y_state[1] as f32),
+                acceleration: (x_state[2] as f32, y_state[2] as f32),
+                time,
+            });
+            prev_pos = blended_pos;
+        }
+        out
+    }
+}
+
+#[test]
+fn stable_after_min_iterations() {
+    let params = KalmanPredictorParams {
+        min_stable_iteration: 3,
+        ..KalmanPredictorParams::suggested()
+    };
+    let mut predictor = KalmanPredictor::new(params, (0.0, 0.0), 0.0);
+    assert!(predictor.predict().is_empty());
+
+    for i in 1..3 {
+        predictor.update((i as f32, 0.0), i as f64 / 180.);
+        assert!(predictor.predict().is_empty());
+    }
+    predictor.update((3.0, 0.0), 3.0 / 180.);
+    assert!(!predictor.predict().is_empty());
+}
+
+#[test]
+fn error_exceeding_recent_travel_ratio_yields_an_empty_tail() {
+    let params = KalmanPredictorParams {
+        min_stable_iteration: 3,
+        max_error_to_travel_ratio: 1e-6,
+        ..KalmanPredictorParams::suggested()
+    };
+    let mut predictor = KalmanPredictor::new(params, (0.0, 0.0), 0.0);
+    for i in 1..=3 {
+        predictor.update((i as f32, 0.0), i as f64 / 180.);
+    }
+    // a vanishingly small ratio rejects the tail regardless of how stable the filter is
+    assert!(predictor.predict().is_empty());
+}
+
+#[test]
+fn validate_rejects_non_positive_error_to_travel_ratio() {
+    let params = KalmanPredictorParams {
+        max_error_to_travel_ratio: 0.0,
+        ..KalmanPredictorParams::suggested()
+    };
+    assert!(params.validate().is_err());
+}
+
+#[test]
+fn validate_rejects_equal_speed_bounds() {
+    let params = KalmanPredictorParams {
+        min_travel_speed: 1.0,
+        max_travel_speed: 1.0,
+        ..KalmanPredictorParams::suggested()
+    };
+    assert!(params.validate().is_err());
+}
+
+#[test]
+fn speed_near_the_floor_yields_a_shorter_tail_than_speed_near_the_ceiling() {
+    // same straight-line, constant-speed motion in both cases, just scaled differently, so
+    // the only thing that should change the predicted tail length is the speed confidence
+    // factor (a normalized position between `min_travel_speed` and `max_travel_speed`)
+    let params = KalmanPredictorParams {
+        min_stable_iteration: 3,
+        desired_number_of_samples: 20,
+        min_travel_speed: 10.0,
+        max_travel_speed: 1000.0,
+        ..KalmanPredictorParams::suggested()
+    };
+    let dt = 1. / 180.;
+
+    // 2 * min_stable_iteration updates, so both predictors are fully matured and the only
+    // remaining difference between them is the speed confidence factor. Speeds are per unit
+    // of time, not per update: 0.1 per 1/180 is 18 (near the floor of 10), 5.0 per 1/180 is
+    // 900 (near the ceiling of 1000)
+    let mut slow = KalmanPredictor::new(params, (0.0, 0.0), 0.0);
+    for i in 1..=6 {
+        slow.update((i as f32 * 0.1, 0.0), i as f64 * dt);
+    }
+
+    let mut fast = KalmanPredictor::new(params, (0.0, 0.0), 0.0);
+    for i in 1..=6 {
+        fast.update((i as f32 * 5.0, 0.0), i as f64 * dt);
+    }
+
+    assert!(slow.predict().len() < fast.predict().len());
+}
+
+#[test]
+fn predict_grows_more_confident_as_samples_accumulate_past_min_stable_iteration() {
+    // same straight-line, constant-speed motion fed to two predictors that differ only in
+    // how many updates they've seen past `min_stable_iteration`: just-stable vs. fully
+    // matured (2x the floor). The just-stable one should predict a shorter (or equal) tail.
+    let params = KalmanPredictorParams {
+        min_stable_iteration: 4,
+        desired_number_of_samples: 20,
+        min_travel_speed: 10.0,
+        max_travel_speed: 1000.0,
+        ..KalmanPredictorParams::suggested()
+    };
+    let dt = 1. / 180.;
+
+    let mut just_stable = KalmanPredictor::new(params, (0.0, 0.0), 0.0);
+    for i in 1..=4 {
+        just_stable.update((i as f32 * 20.0, 0.0), i as f64 * dt);
+    }
+
+    let mut fully_matured = KalmanPredictor::new(params, (0.0, 0.0), 0.0);
+    for i in 1..=8 {
+        fully_matured.update((i as f32 * 20.0, 0.0), i as f64 * dt);
+    }
+
+    assert!(just_stable.predict().len() <= fully_matured.predict().len());
+}
+
+#[test]
+fn predict_stops_once_decaying_velocity_drops_below_min_travel_speed() {
+    // a stroke that is clearly decelerating towards a stop (position approaching an
+    // asymptote) should make the filter's estimated velocity shrink step by step as the
+    // predicted tail is forward-integrated, so the tail should end well short of
+    // desired_number_of_samples rather than being padded out with near-stationary points
+    let params = KalmanPredictorParams {
+        min_stable_iteration: 5,
+        desired_number_of_samples: 50,
+        min_travel_speed: 50.0,
+        ..KalmanPredictorParams::suggested()
+    };
+    let mut predictor = KalmanPredictor::new(params, (0.0, 0.0), 0.0);
+    let dt = 1. / 180.;
+    for i in 1..=10 {
+        let x = 1000.0 * (1.0 - 0.7f32.powi(i));
+        predictor.update((x, 0.0), i as f64 * dt);
+    }
+
+    let prediction = predictor.predict();
+    assert!(!prediction.is_empty());
+    assert!(prediction.len() < params.desired_number_of_samples);
+}
@@ -1,16 +1,70 @@
+use crate::scalar::Scalar;
+
 /// result struct
 /// contains the position, time, presusre as well as the velocity and acceleration data
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct ModelerResult {
     pub pos: (f32, f32),
     pub velocity: (f32, f32),
     pub acceleration: (f32, f32),
     pub time: f64,
     pub pressure: f32,
+    /// stylus tilt angle, in radians, interpolated from the raw inputs.
+    /// `None` when the inputs around this result did not report it
+    pub tilt: Option<f64>,
+    /// stylus orientation angle, in radians, interpolated from the raw inputs.
+    /// `None` when the inputs around this result did not report it
+    pub orientation: Option<f64>,
+}
+
+#[cfg(feature = "glam")]
+impl ModelerResult {
+    /// [Self::pos] as a [glam::Vec2], for callers already working in `glam`.
+    pub fn pos_vec2(&self) -> glam::Vec2 {
+        glam::Vec2::new(self.pos.0, self.pos.1)
+    }
+
+    /// [Self::velocity] as a [glam::Vec2].
+    pub fn velocity_vec2(&self) -> glam::Vec2 {
+        glam::Vec2::new(self.velocity.0, self.velocity.1)
+    }
+
+    /// [Self::acceleration] as a [glam::Vec2].
+    pub fn acceleration_vec2(&self) -> glam::Vec2 {
+        glam::Vec2::new(self.acceleration.0, self.acceleration.1)
+    }
+}
+
+impl ModelerResult {
+    /// [Self::pos] converted to any [Scalar], for callers whose own geometry types use a
+    /// different float representation than this crate's `f32`.
+    pub fn pos_scalar<F: Scalar>(&self) -> (F, F) {
+        (
+            F::from_f32(self.pos.0).unwrap_or_else(F::zero),
+            F::from_f32(self.pos.1).unwrap_or_else(F::zero),
+        )
+    }
+
+    /// [Self::velocity] converted to any [Scalar].
+    pub fn velocity_scalar<F: Scalar>(&self) -> (F, F) {
+        (
+            F::from_f32(self.velocity.0).unwrap_or_else(F::zero),
+            F::from_f32(self.velocity.1).unwrap_or_else(F::zero),
+        )
+    }
+
+    /// [Self::acceleration] converted to any [Scalar].
+    pub fn acceleration_scalar<F: Scalar>(&self) -> (F, F) {
+        (
+            F::from_f32(self.acceleration.0).unwrap_or_else(F::zero),
+            F::from_f32(self.acceleration.1).unwrap_or_else(F::zero),
+        )
+    }
 }
 
 /// A [ModelerResult] that does not have yet a pressure information
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct ModelerPartial {
     pub pos: (f32, f32),
     pub velocity: (f32, f32),
@@ -18,18 +72,60 @@ pub(crate) struct ModelerPartial {
     pub time: f64,
 }
 
-impl ModelerResult {
-    #[cfg(test)]
-    pub fn near(self, other: ModelerResult) -> bool {
-        let tol = 1e-4;
-        approx::abs_diff_eq!(self.pos.0, other.pos.0, epsilon = tol)
-            && approx::abs_diff_eq!(self.pos.1, other.pos.1, epsilon = tol)
-            && approx::abs_diff_eq!(self.time, other.time, epsilon = tol as f64)
-            && approx::abs_diff_eq!(self.acceleration.0, other.acceleration.0, epsilon = tol)
-            && approx::abs_diff_eq!(self.acceleration.1, other.acceleration.1, epsilon = tol)
-            && approx::abs_diff_eq!(self.velocity.0, other.velocity.0, epsilon = tol)
-            && approx::abs_diff_eq!(self.velocity.1, other.velocity.1, epsilon = tol)
-            && approx::abs_diff_eq!(self.pressure, other.pressure, epsilon = tol)
+/// approximate equality with caller-configurable tolerances, for comparing modeled output
+/// where exact floating-point equality is not meaningful
+pub trait ApproxEq {
+    /// the per-field tolerances accepted by [ApproxEq::near_with_tolerance]
+    type Tolerance;
+
+    /// returns whether `self` and `other` are equal within `tolerance`
+    fn near_with_tolerance(&self, other: &Self, tolerance: Self::Tolerance) -> bool;
+
+    /// returns whether `self` and `other` are equal within the crate's default tolerances
+    fn near(&self, other: &Self) -> bool;
+}
+
+/// default per-field tolerance used by [ApproxEq::near]
+const DEFAULT_TOL: f32 = 1e-4;
+
+impl ApproxEq for (f32, f32) {
+    type Tolerance = f32;
+
+    fn near_with_tolerance(&self, other: &Self, tolerance: f32) -> bool {
+        (self.0 - other.0).abs() <= tolerance && (self.1 - other.1).abs() <= tolerance
+    }
+
+    fn near(&self, other: &Self) -> bool {
+        self.near_with_tolerance(other, DEFAULT_TOL)
+    }
+}
+
+impl ApproxEq for ModelerResult {
+    /// `(pos_tol, vel_tol, accel_tol, time_tol)`; `pos_tol` also bounds the pressure
+    /// comparison, there being no dedicated pressure tolerance
+    type Tolerance = (f32, f32, f32, f64);
+
+    fn near_with_tolerance(
+        &self,
+        other: &Self,
+        (pos_tol, vel_tol, accel_tol, time_tol): Self::Tolerance,
+    ) -> bool {
+        self.pos.near_with_tolerance(&other.pos, pos_tol)
+            && self.velocity.near_with_tolerance(&other.velocity, vel_tol)
+            && self
+                .acceleration
+                .near_with_tolerance(&other.acceleration, accel_tol)
+            && (self.time - other.time).abs() <= time_tol
+            && (self.pressure - other.pressure).abs() <= pos_tol
+            && self.tilt == other.tilt
+            && self.orientation == other.orientation
+    }
+
+    fn near(&self, other: &Self) -> bool {
+        self.near_with_tolerance(
+            other,
+            (DEFAULT_TOL, DEFAULT_TOL, DEFAULT_TOL, DEFAULT_TOL as f64),
+        )
     }
 }
 
@@ -41,6 +137,8 @@ impl Default for ModelerResult {
             acceleration: (0.0, 0.0),
             pressure: 1.0,
             time: 0.0,
+            tilt: None,
+            orientation: None,
         }
     }
 }
@@ -76,6 +174,49 @@ pub(crate) fn compare_results(left: Vec<ModelerResult>, right: Vec<ModelerResult
             println!("{:?}", el);
         }
 
-        left.into_iter().zip(right).all(|x| x.0.near(x.1))
+        left.into_iter().zip(right).all(|x| x.0.near(&x.1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn near_accepts_default_tolerance_and_rejects_beyond_it() {
+        let a = ModelerResult {
+            pos: (1.0, 1.0),
+            ..ModelerResult::default()
+        };
+        let b = ModelerResult {
+            pos: (1.0 + DEFAULT_TOL / 2.0, 1.0),
+            ..ModelerResult::default()
+        };
+        let c = ModelerResult {
+            pos: (1.5, 1.0),
+            ..ModelerResult::default()
+        };
+
+        assert!(a.near(&b));
+        assert!(!a.near(&c));
+    }
+
+    #[test]
+    fn near_with_tolerance_applies_a_distinct_tolerance_per_field() {
+        let a = ModelerResult {
+            pos: (0.0, 0.0),
+            velocity: (1.0, 0.0),
+            time: 0.0,
+            ..ModelerResult::default()
+        };
+        let b = ModelerResult {
+            pos: (0.0, 0.0),
+            velocity: (1.2, 0.0),
+            time: 0.0,
+            ..ModelerResult::default()
+        };
+
+        assert!(!a.near_with_tolerance(&b, (0.01, 0.01, 0.01, 0.01)));
+        assert!(a.near_with_tolerance(&b, (0.01, 0.5, 0.01, 0.01)));
     }
 }
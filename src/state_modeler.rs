@@ -1,4 +1,4 @@
-use crate::utils::{dist, interp, interp2, nearest_point_on_segment};
+use crate::utils::{dist, interp2, interp_angle, nearest_point_on_segment, raw_projection_ratio};
 use crate::ModelerInput;
 
 // only imported for docstrings
@@ -9,19 +9,78 @@ use crate::ModelerResult;
 
 use std::collections::VecDeque;
 
-/// Get the pressure for a position by querying
+/// The stylus state interpolated by [StateModeler::query] for a given position
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct StylusState {
+    pub pressure: f32,
+    pub tilt: Option<f64>,
+    pub orientation: Option<f64>,
+}
+
+/// How [StateModeler::query] resolves a position beyond the start or end of the
+/// recorded stroke
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExtrapolationMode {
+    /// repeats the nearest endpoint's values, as [StateModeler] has always done
+    #[default]
+    Clamp,
+    /// continues the nearest segment's per-channel slope past its endpoint
+    Linear,
+}
+
+/// Configures the neutral values [StateModeler::query] reports before any input has been
+/// recorded, and how it behaves for positions beyond the start or end of the recorded stroke
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StylusStateConfig {
+    /// pressure reported when no input has been recorded yet
+    pub default_pressure: f32,
+    /// tilt reported when no input has been recorded yet
+    pub default_tilt: Option<f64>,
+    /// orientation reported when no input has been recorded yet
+    pub default_orientation: Option<f64>,
+    /// how to resolve a position beyond the start or end of the recorded stroke
+    pub extrapolation: ExtrapolationMode,
+}
+
+impl Default for StylusStateConfig {
+    fn default() -> Self {
+        Self {
+            default_pressure: 1.0,
+            default_tilt: None,
+            default_orientation: None,
+            extrapolation: ExtrapolationMode::Clamp,
+        }
+    }
+}
+
+/// Get the pressure, tilt and orientation for a position by querying
 /// information from the raw input strokes
 ///
 /// All raw input strokes are to be provided to this state modeler by calling `update`
 /// Then [ModelerPartial] structs can be converted to [ModelerResult] by querying the
-/// pressure data by calling this struct with the `query` function
+/// stylus state data by calling this struct with the `query` function
 #[doc = include_str!("../docs/notations.html")]
 #[doc = include_str!("../docs/stylus_state_modeler.html")]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct StateModeler {
     /// max number of elements
     stylus_state_modeler_max_input_samples: usize,
     /// deque holding the data from strokes
     last_strokes: VecDeque<ModelerInput>,
+    /// index of the segment matched by the previous [StateModeler::query] call, used to seed
+    /// the next search. `None` right after construction/reset, or once a pop from
+    /// [StateModeler::update] has invalidated it.
+    last_match_index: Option<usize>,
+    /// number of [StateModeler::query] calls served by [StateModeler::incremental_search] since
+    /// the last [StateModeler::full_scan] resync; reset to 0 whenever a full scan runs, and
+    /// forces one once it reaches [Self::FULL_SCAN_RESYNC_INTERVAL], as a safety net against
+    /// drift beyond what [Self::incremental_search]'s patience can catch
+    queries_since_resync: usize,
+    /// default values and extrapolation policy, see [StylusStateConfig]
+    config: StylusStateConfig,
 }
 
 impl Default for StateModeler {
@@ -29,16 +88,33 @@ impl Default for StateModeler {
         Self {
             stylus_state_modeler_max_input_samples: 10,
             last_strokes: VecDeque::with_capacity(11),
+            last_match_index: None,
+            queries_since_resync: 0,
+            config: StylusStateConfig::default(),
         }
     }
 }
 
 impl StateModeler {
+    /// consecutive non-improving segments [StateModeler::incremental_search] tolerates, per
+    /// direction, before giving up on that direction. `1` is enough to step past a single bad
+    /// segment (e.g. a hairpin's cap) and reach a real improvement just beyond it, without
+    /// degrading into an `O(n)` walk over every segment on an uncooperative stroke.
+    const INCREMENTAL_SEARCH_PATIENCE: usize = 1;
+    /// how many [StateModeler::query] calls [StateModeler::incremental_search] serves before
+    /// [StateModeler::query] forces a [StateModeler::full_scan] resync, bounding how far a
+    /// pathological stroke (beyond what `INCREMENTAL_SEARCH_PATIENCE` hairpins can catch) can
+    /// drift the cached match from the true nearest segment.
+    const FULL_SCAN_RESYNC_INTERVAL: usize = 64;
+
     /// initialize a new StateModeler
-    pub(crate) fn new(param: usize) -> Self {
+    pub(crate) fn new(param: usize, config: StylusStateConfig) -> Self {
         Self {
             stylus_state_modeler_max_input_samples: param,
             last_strokes: VecDeque::with_capacity(param + 1),
+            last_match_index: None,
+            queries_since_resync: 0,
+            config,
         }
     }
 
@@ -48,66 +124,251 @@ impl StateModeler {
         self.last_strokes.push_back(input);
         if self.last_strokes.len() > self.stylus_state_modeler_max_input_samples {
             self.last_strokes.pop_front();
+            // every remaining segment index shifted down by one; a cached index of 0
+            // pointed at the segment that was just dropped, so it no longer applies
+            self.last_match_index = self.last_match_index.and_then(|idx| idx.checked_sub(1));
         }
     }
 
     /// reset the StateModeler
-    pub(crate) fn reset(&mut self, max_input: usize) {
+    pub(crate) fn reset(&mut self, max_input: usize, config: StylusStateConfig) {
         self.last_strokes = VecDeque::new();
         self.stylus_state_modeler_max_input_samples = max_input;
+        self.last_match_index = None;
+        self.queries_since_resync = 0;
+        self.config = config;
     }
 
-    /// query the pressure by interpolating it from raw input events
-    pub(crate) fn query(&mut self, pos: (f32, f32)) -> f32 {
-        // iterate over the deque
-        match self.last_strokes.len() {
-            0 => 1.0,
-            1 => return self.last_strokes.front().unwrap().pressure,
-            _ => {
-                let mut distance = f32::INFINITY;
-                let mut r: f32 = 0.0;
+    /// the distance from `pos` to the nearest point on segment `index`, and the parameter `r`
+    /// (in `[0,1]`) of that nearest point along the segment
+    fn segment_match(&self, pos: (f32, f32), index: usize) -> (f32, f32) {
+        let start = self.last_strokes.get(index).unwrap();
+        let end = self.last_strokes.get(index + 1).unwrap();
+        let start_pos = (start.pos.0 as f32, start.pos.1 as f32);
+        let end_pos = (end.pos.0 as f32, end.pos.1 as f32);
+        let r = nearest_point_on_segment(start_pos, end_pos, pos);
+        let point_c = interp2(start_pos, end_pos, r);
+        (dist(pos, point_c), r)
+    }
+
+    /// brute-force search over every segment, returning the index, parameter `r` and distance
+    /// of the globally nearest one
+    fn full_scan(&self, pos: (f32, f32)) -> (usize, f32, f32) {
+        let mut best_index = 0;
+        let mut best_r = 0.0;
+        let mut best_distance = f32::INFINITY;
+
+        for index in 0..self.last_strokes.len() - 1 {
+            let (distance, r) = self.segment_match(pos, index);
+            if distance < best_distance {
+                best_distance = distance;
+                best_index = index;
+                best_r = r;
+            }
+        }
+
+        (best_index, best_r, best_distance)
+    }
 
-                let mut start_pressure: f32 = 1.0;
-                let mut end_pressure: f32 = 1.0;
+    /// searches outward from `start`, in both directions, tolerating up to
+    /// [Self::INCREMENTAL_SEARCH_PATIENCE] consecutive non-improving segments per direction
+    /// before giving up on it. Returns the index, parameter `r` and distance of the best match
+    /// this finds.
+    ///
+    /// This relies on queries arriving in roughly monotonic arc-length order along the
+    /// stroke, so that the segment nearest a query is close to the segment matched by the
+    /// previous query: it searches a local neighbourhood rather than proving a global
+    /// minimum the way [StateModeler::full_scan] does. That assumption breaks down on a
+    /// self-intersecting or looping stroke (e.g. a cursive "e" or a hairpin turn), where
+    /// distance-to-segment is not unimodal around `start`: the globally nearest segment can
+    /// lie past a locally-worse stretch. `INCREMENTAL_SEARCH_PATIENCE` lets the search step
+    /// past a single bad segment like a hairpin's cap rather than giving up right there, while
+    /// [StateModeler::query]'s periodic [StateModeler::full_scan] resync catches drift beyond
+    /// what that patience can.
+    fn incremental_search(&self, pos: (f32, f32), start: usize) -> (usize, f32, f32) {
+        let n_segments = self.last_strokes.len() - 1;
+        let (mut best_distance, mut best_r) = self.segment_match(pos, start);
+        let mut best_index = start;
 
-                for index_it in 0..self.last_strokes.len() - 1 {
-                    let start_pos = self.last_strokes.get(index_it).unwrap().pos;
-                    let end_pos = self.last_strokes.get(index_it + 1).unwrap().pos;
+        let mut index = start;
+        let mut misses = 0;
+        while index > 0 {
+            index -= 1;
+            let (distance, r) = self.segment_match(pos, index);
+            if distance < best_distance {
+                best_distance = distance;
+                best_index = index;
+                best_r = r;
+                misses = 0;
+            } else {
+                misses += 1;
+                if misses > Self::INCREMENTAL_SEARCH_PATIENCE {
+                    break;
+                }
+            }
+        }
 
-                    let r_c = nearest_point_on_segment(start_pos, end_pos, pos);
-                    let point_c = interp2(start_pos, end_pos, r_c);
+        let mut index = start;
+        let mut misses = 0;
+        while index + 1 < n_segments {
+            index += 1;
+            let (distance, r) = self.segment_match(pos, index);
+            if distance < best_distance {
+                best_distance = distance;
+                best_index = index;
+                best_r = r;
+                misses = 0;
+            } else {
+                misses += 1;
+                if misses > Self::INCREMENTAL_SEARCH_PATIENCE {
+                    break;
+                }
+            }
+        }
+
+        (best_index, best_r, best_distance)
+    }
 
-                    if dist(pos, point_c) < distance {
-                        distance = dist(pos, point_c);
-                        r = r_c;
-                        start_pressure = self.last_strokes.get(index_it).unwrap().pressure;
-                        end_pressure = self.last_strokes.get(index_it + 1).unwrap().pressure;
+    /// query the pressure, tilt and orientation by interpolating them from raw input events
+    ///
+    /// pressure, tilt and orientation are all located via the same nearest-segment search
+    /// (bounded by `stylus_state_modeler_max_input_samples`), but orientation is an angle
+    /// that wraps at `2*PI`, so it is interpolated along the shorter arc between the two
+    /// endpoint angles rather than with plain linear interpolation. If either endpoint of
+    /// the nearest segment does not report a value, the result is `None` rather than an
+    /// interpolation between a real value and a placeholder one.
+    pub(crate) fn query(&mut self, pos: (f32, f32)) -> StylusState {
+        // iterate over the deque
+        match self.last_strokes.len() {
+            0 => StylusState {
+                pressure: self.config.default_pressure,
+                tilt: self.config.default_tilt,
+                orientation: self.config.default_orientation,
+            },
+            1 => {
+                let only = self.last_strokes.front().unwrap();
+                StylusState {
+                    pressure: only.pressure as f32,
+                    tilt: only.tilt,
+                    orientation: only.orientation,
+                }
+            }
+            _ => {
+                let n_segments = self.last_strokes.len() - 1;
+                // `incremental_search` only explores a local neighbourhood around `cached`,
+                // bounded by its patience (see its doc comment), rather than proving a global
+                // minimum the way `full_scan` does. A periodic resync keeps it from drifting
+                // away from the true nearest segment indefinitely on a stroke it can't follow,
+                // without paying `full_scan`'s full cost on every single query.
+                let (index, r) = match self.last_match_index {
+                    Some(cached)
+                        if cached < n_segments
+                            && self.queries_since_resync < Self::FULL_SCAN_RESYNC_INTERVAL =>
+                    {
+                        self.queries_since_resync += 1;
+                        let (index, r, _) = self.incremental_search(pos, cached);
+                        (index, r)
                     }
+                    _ => {
+                        self.queries_since_resync = 0;
+                        let (index, r, _) = self.full_scan(pos);
+                        (index, r)
+                    }
+                };
+                self.last_match_index = Some(index);
+
+                let start = self.last_strokes.get(index).unwrap();
+                let end = self.last_strokes.get(index + 1).unwrap();
+                let start_pos = (start.pos.0 as f32, start.pos.1 as f32);
+                let end_pos = (end.pos.0 as f32, end.pos.1 as f32);
+                let r = self.extrapolated_ratio(pos, index, n_segments, r, start_pos, end_pos);
+
+                StylusState {
+                    pressure: interp_raw(start.pressure as f32, end.pressure as f32, r),
+                    tilt: interp_optional(start.tilt, end.tilt, r),
+                    orientation: interp_angle_optional(start.orientation, end.orientation, r),
                 }
+            }
+        }
+    }
+
+    /// Past the very first or very last segment of the recorded stroke, `r` (already clamped
+    /// to `[0,1]` by the nearest-segment search) hides how far `pos` actually lies beyond that
+    /// endpoint. Under [ExtrapolationMode::Linear], this recovers the unclamped ratio so the
+    /// caller can continue the segment's slope instead of repeating the endpoint value;
+    /// under [ExtrapolationMode::Clamp] (the default), `r` is returned unchanged.
+    fn extrapolated_ratio(
+        &self,
+        pos: (f32, f32),
+        index: usize,
+        n_segments: usize,
+        r: f32,
+        start_pos: (f32, f32),
+        end_pos: (f32, f32),
+    ) -> f32 {
+        if self.config.extrapolation != ExtrapolationMode::Linear {
+            return r;
+        }
 
-                interp(start_pressure, end_pressure, r)
+        if index == 0 {
+            let raw = raw_projection_ratio(start_pos, end_pos, pos);
+            if raw < 0.0 {
+                return raw;
             }
         }
+        if index == n_segments - 1 {
+            let raw = raw_projection_ratio(start_pos, end_pos, pos);
+            if raw > 1.0 {
+                return raw;
+            }
+        }
+
+        r
+    }
+}
+
+/// interpolates (without clamping `interp_amount`) for `f32` channels such as pressure
+fn interp_raw(start: f32, end: f32, interp_amount: f32) -> f32 {
+    start + (end - start) * interp_amount
+}
+
+/// interpolates between two optional values (without clamping `interp_amount`, so a caller
+/// can extrapolate beyond the two endpoints), passing through `None` if either endpoint is
+/// `None` rather than interpolating against a placeholder value
+fn interp_optional(start: Option<f64>, end: Option<f64>, interp_amount: f32) -> Option<f64> {
+    match (start, end) {
+        (Some(start), Some(end)) => Some(start + (end - start) * interp_amount as f64),
+        _ => None,
+    }
+}
+
+/// interpolates between two optional angles via [interp_angle] (without clamping
+/// `interp_amount`, so a caller can extrapolate beyond the two endpoints), passing through
+/// `None` if either endpoint is `None` rather than interpolating against a placeholder value
+fn interp_angle_optional(start: Option<f64>, end: Option<f64>, interp_amount: f32) -> Option<f64> {
+    match (start, end) {
+        (Some(start), Some(end)) => Some(interp_angle(start, end, interp_amount as f64)),
+        _ => None,
     }
 }
 
 #[test]
 fn state_modeler_straight() {
-    let mut state_mod = StateModeler::new(10);
-    approx::assert_relative_eq!(state_mod.query((0.0, 0.0)), 1.0); // 1 is our "unknown" default value
-    approx::assert_relative_eq!(state_mod.query((-5.0, 3.0)), 1.0); // 1 is our "unknown" default value
+    let mut state_mod = StateModeler::new(10, StylusStateConfig::default());
+    approx::assert_relative_eq!(state_mod.query((0.0, 0.0)).pressure, 1.0); // 1 is our "unknown" default value
+    approx::assert_relative_eq!(state_mod.query((-5.0, 3.0)).pressure, 1.0); // 1 is our "unknown" default value
 }
 #[test]
 fn query_single_output() {
-    let mut state_mod = StateModeler::new(10);
+    let mut state_mod = StateModeler::new(10, StylusStateConfig::default());
     state_mod.update(ModelerInput {
         pos: (0.0, 0.0),
         pressure: 0.75,
         ..ModelerInput::default()
     });
 
-    approx::assert_relative_eq!(state_mod.query((0.0, 0.0)), 0.75);
-    approx::assert_relative_eq!(state_mod.query((1.0, 1.0)), 0.75);
+    approx::assert_relative_eq!(state_mod.query((0.0, 0.0)).pressure, 0.75);
+    approx::assert_relative_eq!(state_mod.query((1.0, 1.0)).pressure, 0.75);
 }
 
 #[test]
@@ -135,15 +396,151 @@ fn query_multiple_output() {
     });
 
     let tol = 1e-5;
-    approx::assert_abs_diff_eq!(state_mod.query((0.0, 2.0)), 0.3, epsilon = tol);
-    approx::assert_abs_diff_eq!(state_mod.query((1.0, 2.0)), 0.4, epsilon = tol);
-    approx::assert_abs_diff_eq!(state_mod.query((2.0, 1.5)), 0.6, epsilon = tol);
-    approx::assert_abs_diff_eq!(state_mod.query((2.5, 1.875)), 0.65, epsilon = tol);
-    approx::assert_abs_diff_eq!(state_mod.query((2.5, 3.125)), 0.75, epsilon = tol);
-    approx::assert_abs_diff_eq!(state_mod.query((2.5, 4.0)), 0.8, epsilon = tol);
-    approx::assert_abs_diff_eq!(state_mod.query((2.5, 4.0)), 0.8, epsilon = tol);
-    approx::assert_abs_diff_eq!(state_mod.query((3.0, 4.0)), 0.5, epsilon = tol);
-    approx::assert_abs_diff_eq!(state_mod.query((4.0, 4.0)), 0.2, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((0.0, 2.0)).pressure, 0.3, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((1.0, 2.0)).pressure, 0.4, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((2.0, 1.5)).pressure, 0.6, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((2.5, 1.875)).pressure, 0.65, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((2.5, 3.125)).pressure, 0.75, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((2.5, 4.0)).pressure, 0.8, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((2.5, 4.0)).pressure, 0.8, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((3.0, 4.0)).pressure, 0.5, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((4.0, 4.0)).pressure, 0.2, epsilon = tol);
+}
+
+#[test]
+fn incremental_search_matches_brute_force_for_non_monotonic_queries() {
+    let mut state_mod = StateModeler::default();
+    state_mod.update(ModelerInput {
+        pos: (0.5, 1.5),
+        pressure: 0.3,
+        ..Default::default()
+    });
+    state_mod.update(ModelerInput {
+        pos: (2.0, 1.5),
+        pressure: 0.6,
+        ..Default::default()
+    });
+    state_mod.update(ModelerInput {
+        pos: (3.0, 3.5),
+        pressure: 0.8,
+        ..Default::default()
+    });
+    state_mod.update(ModelerInput {
+        pos: (3.5, 4.0),
+        pressure: 0.2,
+        ..Default::default()
+    });
+
+    // same positions as query_multiple_output, but queried out of arc-length order so the
+    // cached index from the previous query is frequently a poor starting guess
+    let tol = 1e-5;
+    approx::assert_abs_diff_eq!(state_mod.query((2.5, 4.0)).pressure, 0.8, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((0.0, 2.0)).pressure, 0.3, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((4.0, 4.0)).pressure, 0.2, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((2.0, 1.5)).pressure, 0.6, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((2.5, 1.875)).pressure, 0.65, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((1.0, 2.0)).pressure, 0.4, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((2.5, 3.125)).pressure, 0.75, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((3.0, 4.0)).pressure, 0.5, epsilon = tol);
+}
+
+#[test]
+fn incremental_search_steps_past_a_hairpin_cap_to_the_inbound_leg() {
+    // a hairpin: straight up, then a tiny cap across, then straight back down right next to
+    // the outbound leg. Distance-to-segment is not unimodal around the outbound leg's
+    // segment: it's near 0 on the outbound leg, spikes up across the cap, then drops back to
+    // near 0 on the inbound leg a couple of segments away. A local hill-climb seeded on the
+    // outbound leg that gave up at the first worse segment would stop at the spike and never
+    // reach the (closer) inbound leg; `INCREMENTAL_SEARCH_PATIENCE` carries it past the cap.
+    let mut state_mod = StateModeler::default();
+    state_mod.update(ModelerInput {
+        pos: (0.0, 0.0),
+        pressure: 0.1,
+        ..Default::default()
+    });
+    state_mod.update(ModelerInput {
+        pos: (0.0, 10.0),
+        pressure: 0.2,
+        ..Default::default()
+    });
+    state_mod.update(ModelerInput {
+        pos: (0.01, 10.0),
+        pressure: 0.3,
+        ..Default::default()
+    });
+    state_mod.update(ModelerInput {
+        pos: (0.01, 0.0),
+        pressure: 0.4,
+        ..Default::default()
+    });
+
+    let tol = 1e-5;
+    // seeds last_match_index on the outbound leg (segment 0)
+    approx::assert_abs_diff_eq!(state_mod.query((0.0, 0.5)).pressure, 0.105, epsilon = tol);
+    // the true nearest segment is the inbound leg (segment 2, x=0.01), two segments away from
+    // the seed past the worse cap segment; incremental_search reaches it from the cached seed
+    // without a full scan
+    approx::assert_abs_diff_eq!(state_mod.query((0.01, 1.0)).pressure, 0.39, epsilon = tol);
+}
+
+#[test]
+fn clamp_extrapolation_repeats_the_nearest_endpoint() {
+    let mut state_mod = StateModeler::new(10, StylusStateConfig::default());
+    state_mod.update(ModelerInput {
+        pos: (0.0, 0.0),
+        pressure: 0.2,
+        ..Default::default()
+    });
+    state_mod.update(ModelerInput {
+        pos: (1.0, 0.0),
+        pressure: 0.8,
+        ..Default::default()
+    });
+
+    let tol = 1e-5;
+    approx::assert_abs_diff_eq!(state_mod.query((-1.0, 0.0)).pressure, 0.2, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((2.0, 0.0)).pressure, 0.8, epsilon = tol);
+}
+
+#[test]
+fn linear_extrapolation_continues_the_boundary_segments_slope() {
+    let config = StylusStateConfig {
+        extrapolation: ExtrapolationMode::Linear,
+        ..Default::default()
+    };
+    let mut state_mod = StateModeler::new(10, config);
+    state_mod.update(ModelerInput {
+        pos: (0.0, 0.0),
+        pressure: 0.2,
+        ..Default::default()
+    });
+    state_mod.update(ModelerInput {
+        pos: (1.0, 0.0),
+        pressure: 0.8,
+        ..Default::default()
+    });
+
+    // one unit past either endpoint continues the 0.6/unit slope instead of repeating it
+    let tol = 1e-5;
+    approx::assert_abs_diff_eq!(state_mod.query((-1.0, 0.0)).pressure, -0.4, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((2.0, 0.0)).pressure, 1.4, epsilon = tol);
+    // still matches exactly within the segment
+    approx::assert_abs_diff_eq!(state_mod.query((0.5, 0.0)).pressure, 0.5, epsilon = tol);
+}
+
+#[test]
+fn custom_defaults_are_reported_before_any_input_is_recorded() {
+    let config = StylusStateConfig {
+        default_pressure: 0.5,
+        default_tilt: Some(1.0),
+        default_orientation: Some(2.0),
+        ..Default::default()
+    };
+    let mut state_mod = StateModeler::new(10, config);
+    let state = state_mod.query((0.0, 0.0));
+    approx::assert_relative_eq!(state.pressure, 0.5);
+    assert_eq!(state.tilt, Some(1.0));
+    assert_eq!(state.orientation, Some(2.0));
 }
 
 #[test]
@@ -201,9 +598,13 @@ fn query_stale() {
     });
 
     let tol = 1e-5;
-    approx::assert_abs_diff_eq!(state_mod.query((2.0, 0.0)), 0.6, epsilon = tol);
-    approx::assert_abs_diff_eq!(state_mod.query((1.0, 3.5)), 0.45, epsilon = tol);
-    approx::assert_abs_diff_eq!(state_mod.query((-3.0, 17. / 6.)), 0.5, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((2.0, 0.0)).pressure, 0.6, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((1.0, 3.5)).pressure, 0.45, epsilon = tol);
+    approx::assert_abs_diff_eq!(
+        state_mod.query((-3.0, 17. / 6.)).pressure,
+        0.5,
+        epsilon = tol
+    );
 
     //adds a 11-th point so that the first point is discarded
     state_mod.update(ModelerInput {
@@ -212,9 +613,13 @@ fn query_stale() {
         ..Default::default()
     });
 
-    approx::assert_abs_diff_eq!(state_mod.query((2.0, 0.0)), 0.3, epsilon = tol);
-    approx::assert_abs_diff_eq!(state_mod.query((1.0, 3.5)), 0.3, epsilon = tol);
-    approx::assert_relative_eq!(state_mod.query((-3.0, 17. / 6.)), 0.5, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((2.0, 0.0)).pressure, 0.3, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((1.0, 3.5)).pressure, 0.3, epsilon = tol);
+    approx::assert_relative_eq!(
+        state_mod.query((-3.0, 17. / 6.)).pressure,
+        0.5,
+        epsilon = tol
+    );
 
     state_mod.update(ModelerInput {
         pos: (-8.0, 0.0),
@@ -222,9 +627,13 @@ fn query_stale() {
         ..Default::default()
     });
 
-    approx::assert_abs_diff_eq!(state_mod.query((2.0, 0.0)), 0.9, epsilon = tol);
-    approx::assert_abs_diff_eq!(state_mod.query((1.0, 3.5)), 0.9, epsilon = tol);
-    approx::assert_abs_diff_eq!(state_mod.query((-3.0, 17. / 6.)), 0.9, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((2.0, 0.0)).pressure, 0.9, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((1.0, 3.5)).pressure, 0.9, epsilon = tol);
+    approx::assert_abs_diff_eq!(
+        state_mod.query((-3.0, 17. / 6.)).pressure,
+        0.9,
+        epsilon = tol
+    );
 }
 
 #[test]
@@ -242,10 +651,10 @@ fn query_reset() {
     });
 
     let tol = 1e-5;
-    approx::assert_abs_diff_eq!(state_mod.query((10.0, 12.0)), 0.1, epsilon = tol);
-    state_mod.reset(10);
+    approx::assert_abs_diff_eq!(state_mod.query((10.0, 12.0)).pressure, 0.1, epsilon = tol);
+    state_mod.reset(10, StylusStateConfig::default());
 
-    approx::assert_relative_eq!(state_mod.query((10.0, 12.0)), 1.0);
+    approx::assert_relative_eq!(state_mod.query((10.0, 12.0)).pressure, 1.0);
 
     state_mod.update(ModelerInput {
         pos: (-1.0, 4.0),
@@ -253,7 +662,7 @@ fn query_reset() {
         ..Default::default()
     });
 
-    approx::assert_abs_diff_eq!(state_mod.query((6.0, 7.0)), 0.4, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((6.0, 7.0)).pressure, 0.4, epsilon = tol);
 
     state_mod.update(ModelerInput {
         pos: (-3.0, 0.0),
@@ -261,8 +670,72 @@ fn query_reset() {
         ..Default::default()
     });
 
-    approx::assert_abs_diff_eq!(state_mod.query((-2.0, 2.0)), 0.55, epsilon = tol);
-    approx::assert_abs_diff_eq!(state_mod.query((0.0, 5.0)), 0.4, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((-2.0, 2.0)).pressure, 0.55, epsilon = tol);
+    approx::assert_abs_diff_eq!(state_mod.query((0.0, 5.0)).pressure, 0.4, epsilon = tol);
+}
+
+#[test]
+fn query_interpolates_tilt_and_orientation() {
+    let mut state_mod = StateModeler::default();
+    state_mod.update(ModelerInput {
+        pos: (0.0, 0.0),
+        tilt: Some(0.2),
+        orientation: Some(1.0),
+        ..Default::default()
+    });
+    state_mod.update(ModelerInput {
+        pos: (2.0, 0.0),
+        tilt: Some(0.6),
+        orientation: Some(2.0),
+        ..Default::default()
+    });
+
+    let tol = 1e-5;
+    let state = state_mod.query((1.0, 0.0));
+    approx::assert_abs_diff_eq!(state.tilt.unwrap(), 0.4, epsilon = tol);
+    approx::assert_abs_diff_eq!(state.orientation.unwrap(), 1.5, epsilon = tol);
+}
+
+#[test]
+fn query_passes_through_none_when_endpoint_is_missing() {
+    let mut state_mod = StateModeler::default();
+    state_mod.update(ModelerInput {
+        pos: (0.0, 0.0),
+        tilt: Some(0.2),
+        orientation: None,
+        ..Default::default()
+    });
+    state_mod.update(ModelerInput {
+        pos: (2.0, 0.0),
+        tilt: None,
+        orientation: None,
+        ..Default::default()
+    });
+
+    let state = state_mod.query((1.0, 0.0));
+    // one endpoint is missing tilt, so the interpolated tilt is "unknown" rather than a guess
+    assert_eq!(state.tilt, None);
+    assert_eq!(state.orientation, None);
+}
+
+#[test]
+fn query_interpolates_orientation_along_the_shorter_arc() {
+    let mut state_mod = StateModeler::default();
+    // orientations near 0 and near 2*PI: the short way round passes through 0, not PI
+    state_mod.update(ModelerInput {
+        pos: (0.0, 0.0),
+        orientation: Some(0.1),
+        ..Default::default()
+    });
+    state_mod.update(ModelerInput {
+        pos: (2.0, 0.0),
+        orientation: Some(std::f64::consts::TAU - 0.1),
+        ..Default::default()
+    });
+
+    let tol = 1e-5;
+    let state = state_mod.query((1.0, 0.0));
+    approx::assert_abs_diff_eq!(state.orientation.unwrap(), 0.0, epsilon = tol);
 }
 // remark : we suppose that pressure is always defined
 // and is set to 1 otherwise (both for input and outputs)
@@ -1,5 +1,5 @@
 // utilities
-use std::ops::{Add, Mul, Sub};
+use crate::scalar::Scalar;
 
 // clamp : use clamp(self,min,max) with 0 and 1 for floats
 
@@ -8,70 +8,135 @@ use std::ops::{Add, Mul, Sub};
 /// 0 if value < start
 /// 1 if value > end
 /// and (value - start)/(end - start) otherwise
-pub(crate) fn normalize01_32(start: f32, end: f32, value: f32) -> f32 {
+///
+/// Generic over any [`Scalar`] rather than hardcoded to `f32` — the `_32` in the name is now
+/// historical, kept so existing `f32` call sites don't need to change.
+pub(crate) fn normalize01_32<F: Scalar>(start: F, end: F, value: F) -> F {
     if start == end {
         if value > start {
-            1.0
+            F::one()
         } else {
-            0.0
+            F::zero()
         }
     } else {
-        ((value - start) / (end - start)).clamp(0.0, 1.0)
+        ((value - start) / (end - start))
+            .max(F::zero())
+            .min(F::one())
     }
 }
 
 /// interpolate the value
 ///
 /// normal interpolation clamped to \[0,1\] for the `interp_amount`
-pub(crate) fn interp<T>(start: T, end: T, interp_amount: f32) -> T
-where
-    T: Sub<Output = T>,
-    T: Add<Output = T>,
-    T: Copy,
-    T: Mul<f32, Output = T>,
-{
-    start + (end - start) * interp_amount.clamp(0.0, 1.0)
+pub(crate) fn interp<F: Scalar>(start: F, end: F, interp_amount: F) -> F {
+    start + (end - start) * interp_amount.max(F::zero()).min(F::one())
 }
 
-/// interpolation (with the `interp_amount` clamped between 0 and 1) for `(f32,f32)` types
-pub(crate) fn interp2(start: (f32, f32), end: (f32, f32), interp_amount: f32) -> (f32, f32) {
+/// interpolation (with the `interp_amount` clamped between 0 and 1) for `(F,F)` types
+pub(crate) fn interp2<F: Scalar>(start: (F, F), end: (F, F), interp_amount: F) -> (F, F) {
+    let t = interp_amount.max(F::zero()).min(F::one());
     (
-        start.0 + interp_amount.clamp(0.0, 1.0) * (end.0 - start.0),
-        start.1 + interp_amount.clamp(0.0, 1.0) * (end.1 - start.1),
+        start.0 + t * (end.0 - start.0),
+        start.1 + t * (end.1 - start.1),
     )
 }
 
+/// [interp2], routed through [glam::Vec2] arithmetic instead of a pair of independent `F`
+/// lerps, for callers already working in `glam` who want the same SIMD-friendly vector ops
+/// the rest of their pipeline uses. `interp_amount` is clamped to `[0,1]` exactly like
+/// [interp2].
+#[cfg(feature = "glam")]
+pub(crate) fn interp2_vec2(start: glam::Vec2, end: glam::Vec2, interp_amount: f32) -> glam::Vec2 {
+    start.lerp(end, interp_amount.clamp(0.0, 1.0))
+}
+
 /// returns the point on the line segment from `segment_start` to `segment_end`
 /// that is closest to `point`, represented as the ratio of the length
 /// along the segment
-pub(crate) fn nearest_point_on_segment(
-    start: (f32, f32),
-    end: (f32, f32),
-    point: (f32, f32),
+pub(crate) fn nearest_point_on_segment<F: Scalar>(start: (F, F), end: (F, F), point: (F, F)) -> F {
+    if start == end {
+        F::zero()
+    } else {
+        let seg_vector = (end.0 - start.0, end.1 - start.1);
+        let proj_vector = (point.0 - start.0, point.1 - start.1);
+
+        (dot(proj_vector, seg_vector) / dot(seg_vector, seg_vector))
+            .max(F::zero())
+            .min(F::one())
+    }
+}
+
+/// [nearest_point_on_segment], routed through [glam::Vec2] arithmetic instead of a pair of
+/// independent `F` dot products, for callers already working in `glam`.
+#[cfg(feature = "glam")]
+pub(crate) fn nearest_point_on_segment_vec2(
+    start: glam::Vec2,
+    end: glam::Vec2,
+    point: glam::Vec2,
 ) -> f32 {
     if start == end {
-        0.0_f32
+        0.0
+    } else {
+        let seg_vector = end - start;
+        let proj_vector = point - start;
+        (proj_vector.dot(seg_vector) / seg_vector.dot(seg_vector)).clamp(0.0, 1.0)
+    }
+}
+
+/// the (unclamped) ratio along the line through `start` and `end` of the point on that line
+/// closest to `point`. Unlike [nearest_point_on_segment], the result is not restricted to
+/// `[0,1]`, so it can be used to extrapolate a line beyond its two endpoints.
+pub(crate) fn raw_projection_ratio<F: Scalar>(start: (F, F), end: (F, F), point: (F, F)) -> F {
+    if start == end {
+        F::zero()
     } else {
         let seg_vector = (end.0 - start.0, end.1 - start.1);
         let proj_vector = (point.0 - start.0, point.1 - start.1);
 
-        (dot(proj_vector, seg_vector) / dot(seg_vector, seg_vector)).clamp(0.0, 1.0)
+        dot(proj_vector, seg_vector) / dot(seg_vector, seg_vector)
     }
 }
 
-/// dot product for `(f32,32)` types
-pub(crate) fn dot(x: (f32, f32), y: (f32, f32)) -> f32 {
+/// dot product for `(F,F)` types
+pub(crate) fn dot<F: Scalar>(x: (F, F), y: (F, F)) -> F {
     x.0 * y.0 + x.1 * y.1
 }
 
-/// distance calculation for `(f32,f32)` types
-pub fn dist(start: (f32, f32), end: (f32, f32)) -> f32 {
+/// interpolates between two angles (in radians, wrapping at `2*PI`) along the shorter arc
+/// between them rather than the plain linear path [interp] would take — e.g. interpolating
+/// from `0.1` towards `TAU - 0.1` passes through `0`, not through `PI`. Like [dist] and [dot],
+/// `interp_amount` is not clamped to `[0,1]`, so a caller can extrapolate past either endpoint
+/// and continue along the same shorter-arc direction.
+pub(crate) fn interp_angle<F: Scalar>(start: F, end: F, interp_amount: F) -> F {
+    let pi = F::from_f64(std::f64::consts::PI).unwrap();
+    let two_pi = F::from_f64(std::f64::consts::TAU).unwrap();
+
+    let mut delta = (end - start) % two_pi;
+    if delta > pi {
+        delta = delta - two_pi;
+    } else if delta < -pi {
+        delta = delta + two_pi;
+    }
+
+    let result = (start + delta * interp_amount) % two_pi;
+    if result < F::zero() {
+        result + two_pi
+    } else {
+        result
+    }
+}
+
+/// distance calculation for `(F,F)` types
+pub fn dist<F: Scalar>(start: (F, F), end: (F, F)) -> F {
     ((start.0 - end.0).powi(2) + (start.1 - end.1).powi(2)).sqrt()
 }
 
 #[cfg(test)]
 mod test_utils {
-    use crate::utils::{interp, interp2, nearest_point_on_segment, normalize01_32};
+    use crate::utils::{
+        interp, interp2, interp_angle, nearest_point_on_segment, normalize01_32,
+        raw_projection_ratio,
+    };
 
     #[test]
     fn test_normalize_float() {
@@ -91,6 +156,27 @@ mod test_utils {
         approx::assert_relative_eq!(interp(5.0, 7.0, 20.0), 7.0);
     }
 
+    #[test]
+    fn test_interp_angle() {
+        // away from the wraparound point, behaves just like plain `interp`
+        approx::assert_relative_eq!(interp_angle(0.2, 0.8, 0.5), 0.5);
+
+        // interpolating from just below `TAU` towards just above `0` takes the short way
+        // through `0`, not the long way through `PI`
+        let two_pi = std::f64::consts::TAU;
+        approx::assert_relative_eq!(interp_angle(two_pi - 0.1, 0.1, 0.5), 0.0, epsilon = 1e-9);
+
+        // same wraparound, starting from the other side
+        approx::assert_relative_eq!(interp_angle(0.1, two_pi - 0.1, 0.5), 0.0, epsilon = 1e-9);
+
+        // unlike `interp`, `interp_amount` is not clamped: extrapolating past either endpoint
+        // continues along the same shorter-arc direction instead of stopping at it
+        approx::assert_relative_eq!(
+            interp_angle(0.0, std::f64::consts::FRAC_PI_2, 2.0),
+            std::f64::consts::PI
+        );
+    }
+
     #[test]
     fn test_interp_vec2() {
         assert_eq!(interp2((1.0, 2.0), (3.0, 5.0), 0.5), (2.0, 3.5));
@@ -99,6 +185,20 @@ mod test_utils {
         assert_eq!(interp2((12.0, 5.0), (13.0, 14.0), 3.2), (13.0, 14.0));
     }
 
+    #[test]
+    #[cfg(feature = "glam")]
+    fn test_interp_vec2_matches_interp2() {
+        use crate::utils::interp2_vec2;
+        assert_eq!(
+            interp2_vec2(glam::Vec2::new(1.0, 2.0), glam::Vec2::new(3.0, 5.0), 0.5),
+            glam::Vec2::new(2.0, 3.5)
+        );
+        assert_eq!(
+            interp2_vec2(glam::Vec2::new(7.0, 9.0), glam::Vec2::new(25.0, 30.0), -0.1),
+            glam::Vec2::new(7.0, 9.0)
+        );
+    }
+
     #[test]
     fn test_nearest_point() {
         assert_eq!(
@@ -128,4 +228,52 @@ mod test_utils {
             0.0
         );
     }
+
+    #[test]
+    #[cfg(feature = "glam")]
+    fn test_nearest_point_vec2_matches_nearest_point_on_segment() {
+        use crate::utils::nearest_point_on_segment_vec2;
+        assert_eq!(
+            nearest_point_on_segment_vec2(
+                glam::Vec2::new(0.0, 0.0),
+                glam::Vec2::new(1.0, 0.0),
+                glam::Vec2::new(0.25, 0.5),
+            ),
+            0.25
+        );
+        // degenerate segment
+        assert_eq!(
+            nearest_point_on_segment_vec2(
+                glam::Vec2::new(0.0, 0.0),
+                glam::Vec2::new(0.0, 0.0),
+                glam::Vec2::new(5.0, 10.0),
+            ),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_raw_projection_ratio() {
+        // within the segment, matches nearest_point_on_segment
+        approx::assert_relative_eq!(
+            raw_projection_ratio((0.0, 0.0), (1.0, 0.0), (0.25, 0.5)),
+            0.25
+        );
+
+        // beyond either endpoint, unlike nearest_point_on_segment it is not clamped
+        approx::assert_relative_eq!(
+            raw_projection_ratio((0.0, 0.0), (1.0, 0.0), (-2.0, 0.0)),
+            -2.0
+        );
+        approx::assert_relative_eq!(
+            raw_projection_ratio((0.0, 0.0), (1.0, 0.0), (3.0, 0.0)),
+            3.0
+        );
+
+        // degenerate segment
+        assert_eq!(
+            raw_projection_ratio((0.0, 0.0), (0.0, 0.0), (5.0, 10.0)),
+            0.0
+        );
+    }
 }